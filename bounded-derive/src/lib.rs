@@ -0,0 +1,129 @@
+//! Derive macros for the `bounded` crate
+//!
+//! Use them through the `derive` feature of `bounded` rather than directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, GenericArgument, PathArguments, Type,
+};
+
+/// Forwards the traits implemented by the wrapped `Bounded` to a tuple
+/// struct with a single field
+///
+/// A trait the wrapped `Bounded` doesn't implement, such as `Neg` for an
+/// unsigned one, is left out. `Deref` to the wrapped `Bounded` is only
+/// implemented with `#[bounded(deref)]`.
+///
+/// The primitive is read from the field type, `Bounded<u8, 0, 9>` or
+/// `BoundedU8<0, 9>`, and has to be given with `#[bounded(primitive = u8)]`
+/// when the field type is an alias.
+#[proc_macro_derive(BoundedNewtype, attributes(bounded))]
+pub fn bounded_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    newtype(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn newtype(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            input.generics,
+            "`BoundedNewtype` does not support generic types",
+        ));
+    }
+    let fields = match input.data {
+        Data::Struct(data) => match data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed,
+            fields => {
+                return Err(Error::new_spanned(
+                    fields,
+                    "`BoundedNewtype` expects exactly one unnamed field",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`BoundedNewtype` can only be derived for tuple structs",
+            ))
+        }
+    };
+    let mut deref = false;
+    let mut primitive = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("bounded"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("deref") {
+                deref = true;
+                Ok(())
+            } else if meta.path.is_ident("primitive") {
+                primitive = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `deref` or `primitive`"))
+            }
+        })?;
+    }
+    let name = input.ident;
+    let inner = &fields[0].ty;
+    let primitive =
+        match primitive.or_else(|| primitive_of(inner)) {
+            Some(primitive) => primitive,
+            None => return Err(Error::new_spanned(
+                inner,
+                "cannot read the primitive of the field type, add `#[bounded(primitive = ...)]`",
+            )),
+        };
+    Ok(if deref {
+        quote! {
+            ::bounded::newtype!(#name, #inner, #primitive, deref);
+        }
+    } else {
+        quote! {
+            ::bounded::newtype!(#name, #inner, #primitive);
+        }
+    })
+}
+
+/// Returns `T` of `Bounded<T, MIN, MAX>` or the primitive of an alias such as
+/// `BoundedU8<MIN, MAX>`
+///
+/// The impls can't name `<Inner as Deref>::Target` instead, since coherence
+/// doesn't normalize it outside of `bounded`.
+fn primitive_of(ty: &Type) -> Option<Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    let primitive = match segment.ident.to_string().as_str() {
+        "Bounded" => {
+            return match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+                    GenericArgument::Type(primitive) => Some(primitive.clone()),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        "BoundedI8" => "i8",
+        "BoundedI16" => "i16",
+        "BoundedI32" => "i32",
+        "BoundedI64" => "i64",
+        "BoundedI128" => "i128",
+        "BoundedIsize" => "isize",
+        "BoundedU8" => "u8",
+        "BoundedU16" => "u16",
+        "BoundedU32" => "u32",
+        "BoundedU64" => "u64",
+        "BoundedU128" => "u128",
+        "BoundedUsize" => "usize",
+        _ => return None,
+    };
+    syn::parse_str(primitive).ok()
+}
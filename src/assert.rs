@@ -0,0 +1,51 @@
+//! Assertions on const parameters, evaluated at compile time when referenced
+
+/// `MIN` of a divisor, reinterpreted as `u128` when `UNSIGNED`
+pub(crate) struct Divisor<const MIN: i128, const UNSIGNED: bool>;
+
+impl<const MIN: i128, const UNSIGNED: bool> Divisor<MIN, UNSIGNED> {
+    pub(crate) const POSITIVE: () = assert!(
+        if UNSIGNED { MIN != 0 } else { MIN > 0 },
+        "divisor must be positive"
+    );
+}
+
+pub(crate) struct ArrayIndex<const N: usize, const MAX: i128>;
+
+impl<const N: usize, const MAX: i128> ArrayIndex<N, MAX> {
+    pub(crate) const IN_BOUNDS: () = assert!(
+        0 <= MAX && MAX < N as i128,
+        "index bounds exceed the array length"
+    );
+}
+
+/// Condition on const parameters, used in where clauses of conditional impls
+#[doc(hidden)]
+pub struct Assert<const CONDITION: bool>;
+
+#[doc(hidden)]
+pub trait IsTrue {}
+
+impl IsTrue for Assert<true> {}
+
+/// Whether 0 is in `MIN..=MAX`, where the bounds of an unsigned type are
+/// reinterpreted as `u128`
+#[doc(hidden)]
+pub const fn contains_zero(min: i128, max: i128, unsigned: bool) -> bool {
+    if unsigned {
+        min == 0
+    } else {
+        min <= 0 && 0 <= max
+    }
+}
+
+/// Whether 1 is in `MIN..=MAX`, where the bounds of an unsigned type are
+/// reinterpreted as `u128`
+#[doc(hidden)]
+pub const fn contains_one(min: i128, max: i128, unsigned: bool) -> bool {
+    if unsigned {
+        min as u128 <= 1 && 1 <= max as u128
+    } else {
+        min <= 1 && 1 <= max
+    }
+}
@@ -0,0 +1,25 @@
+use core::sync::atomic::Ordering;
+
+/// Integer bounded by closed interval, which can be safely shared between
+/// threads
+#[repr(transparent)]
+pub struct AtomicBounded<T: AtomicPrimitive, const MIN: i128, const MAX: i128>(
+    pub(crate) T::Atomic,
+);
+
+/// Primitive integer type which has an atomic counterpart
+pub trait AtomicPrimitive: Sealed {
+    type Atomic;
+}
+
+pub trait Sealed {}
+
+/// Returns the strongest ordering valid for the load of a failed
+/// compare-and-swap, given the ordering of the successful one
+pub(crate) const fn failure_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+        Ordering::AcqRel | Ordering::Acquire => Ordering::Acquire,
+        _ => Ordering::SeqCst,
+    }
+}
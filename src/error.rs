@@ -0,0 +1,57 @@
+use core::fmt::{self, Display, Formatter};
+use core::num::ParseIntError;
+use std::error::Error;
+
+/// An error which can be returned when parsing a bounded integer
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Value is not a valid integer
+    Int(ParseIntError),
+    /// Value is out of bounds
+    OutOfBounds,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Int(error) => error.fmt(f),
+            Self::OutOfBounds => f.write_str("value is out of bounds"),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Int(error) => Some(error),
+            Self::OutOfBounds => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseError {
+    fn from(from: ParseIntError) -> Self {
+        Self::Int(from)
+    }
+}
+
+/// An error which can be returned when a bounded collection would leave its
+/// length bounds
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LengthError {
+    /// Length is less than the minimum
+    TooShort,
+    /// Length is greater than the maximum
+    TooLong,
+}
+
+impl Display for LengthError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::TooShort => f.write_str("length is less than the minimum"),
+            Self::TooLong => f.write_str("length is greater than the maximum"),
+        }
+    }
+}
+
+impl Error for LengthError {}
@@ -0,0 +1,56 @@
+use crate::{assert, Bounded};
+use core::ops::{Index, IndexMut};
+
+/// Index of an array of length `N`
+pub type ArrayIndex<const N: usize> = Bounded<usize, 0, { N as i128 - 1 }>;
+
+/// Extension of arrays yielding their indices as bounded integers
+pub trait EnumerateBounded<T, const N: usize> {
+    /// Like `iter().enumerate()`, but the indices are bounded by the length
+    fn enumerate_bounded<'a>(&'a self) -> impl Iterator<Item = (ArrayIndex<N>, &'a T)>
+    where
+        T: 'a,
+        [(); { N as i128 - 1 } as usize]:;
+}
+
+impl<T, const N: usize> EnumerateBounded<T, N> for [T; N] {
+    fn enumerate_bounded<'a>(&'a self) -> impl Iterator<Item = (ArrayIndex<N>, &'a T)>
+    where
+        T: 'a,
+        [(); { N as i128 - 1 } as usize]:,
+    {
+        self.iter()
+            .enumerate()
+            .map(|(index, value)| (Bounded(index), value))
+    }
+}
+
+/// Skips the bounds check, the index being in bounds is checked at compile
+/// time
+///
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// let _ = [0; 4][bounded::Bounded::<usize, 0, 4>::new(0)];
+/// ```
+impl<T, const N: usize, const MIN: i128, const MAX: i128> Index<Bounded<usize, MIN, MAX>>
+    for [T; N]
+{
+    type Output = T;
+
+    fn index(&self, index: Bounded<usize, MIN, MAX>) -> &T {
+        let () = assert::ArrayIndex::<N, MAX>::IN_BOUNDS;
+        // SAFETY: `index <= MAX < N`
+        unsafe { self.get_unchecked(index.0) }
+    }
+}
+
+impl<T, const N: usize, const MIN: i128, const MAX: i128> IndexMut<Bounded<usize, MIN, MAX>>
+    for [T; N]
+{
+    fn index_mut(&mut self, index: Bounded<usize, MIN, MAX>) -> &mut T {
+        let () = assert::ArrayIndex::<N, MAX>::IN_BOUNDS;
+        // SAFETY: `index <= MAX < N`
+        unsafe { self.get_unchecked_mut(index.0) }
+    }
+}
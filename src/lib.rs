@@ -1,34 +1,187 @@
-#![feature(const_generics)]
-#![feature(const_panic)]
-
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+#[cfg(all(test, feature = "derive"))]
+extern crate self as bounded;
+
+#[doc(hidden)]
+pub use assert::{contains_one, contains_zero, Assert, IsTrue};
+pub use atomic::{AtomicBounded, AtomicPrimitive};
+#[cfg(feature = "derive")]
+pub use bounded_derive::BoundedNewtype;
+pub use error::{LengthError, ParseError};
+pub use index::{ArrayIndex, EnumerateBounded};
+pub use map::{BoundedMap, Key};
+#[cfg(feature = "num-traits")]
+#[doc(hidden)]
+pub use num_traits;
 #[cfg(feature = "saturating-div")]
 pub use saturating_div::SaturatingDiv;
+pub use set::BoundedSet;
+pub use string::{BoundedString, Bytes, Chars, LengthUnit};
+#[cfg(feature = "rand")]
+pub use uniform::UniformBounded;
+#[cfg(feature = "clap")]
+pub use value_parser::BoundedValueParser;
+pub use vec::BoundedVec;
 
-use core::fmt::{self, Display, Formatter};
-use core::ops::{Deref, DerefMut};
+use core::fmt::{self, Binary, Display, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex};
+use core::ops::Deref;
 
 /// Integer bounded by closed interval
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-pub struct Bounded<T, const MIN: i128, const MAX: u128>(T);
+///
+/// The alternate debug format (`{:#?}`) also prints the bounds, e.g.
+/// `Bounded(5 in 0..=9)`.
+///
+/// Both bounds may be negative, e.g. `Bounded<i32, -100, -1>`, and `MIN` may
+/// equal `MAX`. `u128` bounds above `i128::MAX` are written as `u128 as i128`,
+/// e.g. `Bounded<u128, 0, { u128::MAX as i128 }>`. The per-primitive aliases,
+/// such as `BoundedU128<0, { u128::MAX }>`, take the bounds as the primitive.
+///
+/// Constructing a value of a type whose bounds are out of the range of `T` or
+/// whose `MIN` is greater than `MAX` fails to compile:
+///
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// let _ = bounded::Bounded::<i8, { i8::MIN as i128 - 1 }, { i8::MAX as i128 }>::new(0);
+/// ```
+///
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// let _ = bounded::Bounded::<i8, { i8::MIN as i128 }, { i8::MAX as i128 + 1 }>::new(0);
+/// ```
+///
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// let _ = bounded::Bounded::<u8, 5, 4>::checked_new(5);
+/// ```
+///
+/// `Default` is 0, so it is only implemented when 0 is in bounds:
+///
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// let _ = bounded::Bounded::<u8, 1, 9>::default();
+/// ```
+///
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// let _ = bounded::Bounded::<u8, 0, 300>::default();
+/// ```
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
+#[repr(transparent)]
+pub struct Bounded<T, const MIN: i128, const MAX: i128>(T);
+
+/// `i8` bounded by closed interval, the same type as the generic form
+pub type BoundedI8<const MIN: i8, const MAX: i8> = Bounded<i8, { MIN as i128 }, { MAX as i128 }>;
+
+/// `i16` bounded by closed interval, the same type as the generic form
+pub type BoundedI16<const MIN: i16, const MAX: i16> =
+    Bounded<i16, { MIN as i128 }, { MAX as i128 }>;
+
+/// `i32` bounded by closed interval, the same type as the generic form
+pub type BoundedI32<const MIN: i32, const MAX: i32> =
+    Bounded<i32, { MIN as i128 }, { MAX as i128 }>;
+
+/// `i64` bounded by closed interval, the same type as the generic form
+pub type BoundedI64<const MIN: i64, const MAX: i64> =
+    Bounded<i64, { MIN as i128 }, { MAX as i128 }>;
+
+/// `i128` bounded by closed interval, the same type as the generic form
+pub type BoundedI128<const MIN: i128, const MAX: i128> = Bounded<i128, MIN, MAX>;
+
+/// `isize` bounded by closed interval, the same type as the generic form
+pub type BoundedIsize<const MIN: isize, const MAX: isize> =
+    Bounded<isize, { MIN as i128 }, { MAX as i128 }>;
+
+/// `u8` bounded by closed interval, the same type as the generic form
+pub type BoundedU8<const MIN: u8, const MAX: u8> = Bounded<u8, { MIN as i128 }, { MAX as i128 }>;
+
+/// `u16` bounded by closed interval, the same type as the generic form
+pub type BoundedU16<const MIN: u16, const MAX: u16> =
+    Bounded<u16, { MIN as i128 }, { MAX as i128 }>;
+
+/// `u32` bounded by closed interval, the same type as the generic form
+pub type BoundedU32<const MIN: u32, const MAX: u32> =
+    Bounded<u32, { MIN as i128 }, { MAX as i128 }>;
+
+/// `u64` bounded by closed interval, the same type as the generic form
+pub type BoundedU64<const MIN: u64, const MAX: u64> =
+    Bounded<u64, { MIN as i128 }, { MAX as i128 }>;
+
+/// `u128` bounded by closed interval, the same type as the generic form
+pub type BoundedU128<const MIN: u128, const MAX: u128> =
+    Bounded<u128, { MIN as i128 }, { MAX as i128 }>;
+
+/// `usize` bounded by closed interval, the same type as the generic form
+pub type BoundedUsize<const MIN: usize, const MAX: usize> =
+    Bounded<usize, { MIN as i128 }, { MAX as i128 }>;
 
 r#impl!(Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
+r#impl!(AbsDiff for Bounded<i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize, u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize>);
+
+#[cfg(target_has_atomic = "8")]
+r#impl!(Atomic for Bounded<i8 => core::sync::atomic::AtomicI8, u8 => core::sync::atomic::AtomicU8>);
+
+#[cfg(target_has_atomic = "16")]
+r#impl!(Atomic for Bounded<i16 => core::sync::atomic::AtomicI16, u16 => core::sync::atomic::AtomicU16>);
+
+#[cfg(target_has_atomic = "32")]
+r#impl!(Atomic for Bounded<i32 => core::sync::atomic::AtomicI32, u32 => core::sync::atomic::AtomicU32>);
+
+#[cfg(target_has_atomic = "64")]
+r#impl!(Atomic for Bounded<i64 => core::sync::atomic::AtomicI64, u64 => core::sync::atomic::AtomicU64>);
+
+#[cfg(target_has_atomic = "ptr")]
+r#impl!(Atomic for Bounded<isize => core::sync::atomic::AtomicIsize, usize => core::sync::atomic::AtomicUsize>);
+
+r#impl!(Sign for Bounded<i8, i16, i32, i64, i128, isize>);
+
 r#impl!(Add for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
 r#impl!(AddAssign for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
+r#impl!(BitAnd for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+r#impl!(BitAndAssign for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+r#impl!(BitOr for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+r#impl!(BitOrAssign for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+r#impl!(BitXor for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+r#impl!(BitXorAssign for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+r#impl!(Debug for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+r#impl!(Default for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
 r#impl!(From for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
 r#impl!(Div for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
 r#impl!(DivAssign for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
+r#impl!(Key for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
 r#impl!(Mul for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
 r#impl!(MulAssign for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
 r#impl!(Neg for Bounded<i8, i16, i32, i64, i128, isize>);
 
+r#impl!(Not for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
 r#impl!(RangeBounds for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
 r#impl!(Rem for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
@@ -74,6 +227,24 @@ r#impl!(CheckedShr for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64
 #[cfg(feature = "checked-sub")]
 r#impl!(CheckedSub for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
+#[cfg(feature = "zerocopy")]
+r#impl!(TryRefFromBytes for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "from-primitive")]
+r#impl!(FromPrimitive for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "num")]
+r#impl!(Num for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "num-cast")]
+r#impl!(NumCast for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "one")]
+r#impl!(One for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "rand")]
+r#impl!(Distribution for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
 #[cfg(feature = "saturating-add")]
 r#impl!(SaturatingAdd for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
@@ -86,7 +257,37 @@ r#impl!(SaturatingMul for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32,
 #[cfg(feature = "saturating-sub")]
 r#impl!(SaturatingSub for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
 
-impl<T, const MIN: i128, const MAX: u128> Deref for Bounded<T, MIN, MAX> {
+#[cfg(feature = "rand")]
+r#impl!(SampleUniform for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "to-primitive")]
+r#impl!(ToPrimitive for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "clap")]
+r#impl!(ValueParserFactory for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "zero")]
+r#impl!(Zero for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "arbitrary")]
+r#impl!(arbitrary::Arbitrary for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "bytemuck")]
+r#impl!(bytemuck::CheckedBitPattern for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "bytemuck")]
+r#impl!(bytemuck::NoUninit for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "bytemuck")]
+r#impl!(bytemuck::Zeroable for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "proptest")]
+r#impl!(proptest::Arbitrary for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+#[cfg(feature = "schemars")]
+r#impl!(schemars::JsonSchema for Bounded<i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize>);
+
+impl<T, const MIN: i128, const MAX: i128> Deref for Bounded<T, MIN, MAX> {
     type Target = T;
 
     fn deref(&self) -> &T {
@@ -94,21 +295,65 @@ impl<T, const MIN: i128, const MAX: u128> Deref for Bounded<T, MIN, MAX> {
     }
 }
 
-impl<T, const MIN: i128, const MAX: u128> DerefMut for Bounded<T, MIN, MAX> {
-    fn deref_mut(&mut self) -> &mut T {
-        &mut self.0
+impl<T: Binary, const MIN: i128, const MAX: i128> Binary for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Display, const MIN: i128, const MAX: i128> Display for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: LowerExp, const MIN: i128, const MAX: i128> LowerExp for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: LowerHex, const MIN: i128, const MAX: i128> LowerHex for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Octal, const MIN: i128, const MAX: i128> Octal for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: UpperExp, const MIN: i128, const MAX: i128> UpperExp for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
     }
 }
 
-impl<T: Display, const MIN: i128, const MAX: u128> Display for Bounded<T, MIN, MAX> {
+impl<T: UpperHex, const MIN: i128, const MAX: i128> UpperHex for Bounded<T, MIN, MAX> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
+mod assert;
+mod atomic;
+mod error;
+mod index;
+mod literal;
 mod macros;
+mod map;
+mod modular;
 #[cfg(feature = "saturating-div")]
 mod saturating_div;
+mod set;
+mod string;
+#[cfg(feature = "rand")]
+mod uniform;
+#[cfg(feature = "clap")]
+mod value_parser;
+mod vec;
 
 #[cfg(test)]
 mod tests;
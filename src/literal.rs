@@ -0,0 +1,65 @@
+//! Panic message of an out of bounds literal, formatted at compile time
+
+const CAPACITY: usize = 160;
+
+pub(crate) struct Message {
+    bytes: [u8; CAPACITY],
+    len: usize,
+}
+
+impl Message {
+    /// Formats `value {value} is out of bounds {min}..={max}`, each number
+    /// given as a sign and a magnitude
+    pub(crate) const fn new(value: (bool, u128), min: (bool, u128), max: (bool, u128)) -> Self {
+        let message = Self {
+            bytes: [0; CAPACITY],
+            len: 0,
+        };
+        let message = message.push_str("value ");
+        let message = message.push_int(value);
+        let message = message.push_str(" is out of bounds ");
+        let message = message.push_int(min);
+        let message = message.push_str("..=");
+        message.push_int(max)
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(str) => str,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    const fn push_str(mut self, str: &str) -> Self {
+        let bytes = str.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            self.bytes[self.len] = bytes[index];
+            self.len += 1;
+            index += 1;
+        }
+        self
+    }
+
+    const fn push_int(mut self, (negative, mut magnitude): (bool, u128)) -> Self {
+        if negative {
+            self = self.push_str("-");
+        }
+        let mut digits = [0; 39];
+        let mut count = 0;
+        loop {
+            digits[count] = b'0' + (magnitude % 10) as u8;
+            count += 1;
+            magnitude /= 10;
+            if magnitude == 0 {
+                break;
+            }
+        }
+        while count > 0 {
+            count -= 1;
+            self.bytes[self.len] = digits[count];
+            self.len += 1;
+        }
+        self
+    }
+}
@@ -2,6 +2,29 @@
 macro_rules! r#impl {
     (Bounded<$type:ty>) => {
         $crate::new!($type);
+        $crate::arithmetic!($type);
+        $crate::euclid!($type);
+        $crate::ilog!($type);
+        $crate::isqrt!($type);
+        $crate::pow!($type);
+    };
+    (AbsDiff for Bounded<$type:ty => $unsigned:ty>) => {
+        $crate::abs_diff!($type, $unsigned);
+    };
+    (Atomic for Bounded<$type:ty => $atomic:ty>) => {
+        $crate::atomic!($type, $atomic);
+    };
+    (Key for Bounded<$type:ty>) => {
+        $crate::key!($type);
+    };
+    (ValueParserFactory for Bounded<$type:ty>) => {
+        $crate::value_parser_factory!($type);
+    };
+    (TryRefFromBytes for Bounded<$type:ty>) => {
+        $crate::try_ref_from_bytes!($type);
+    };
+    (Sign for Bounded<$type:ty>) => {
+        $crate::sign!($type);
     };
     // Traits
     (Add for Bounded<$type:ty>) => {
@@ -10,6 +33,30 @@ macro_rules! r#impl {
     (AddAssign for Bounded<$type:ty>) => {
         $crate::add_assign!($type);
     };
+    (BitAnd for Bounded<$type:ty>) => {
+        $crate::bit_and!($type);
+    };
+    (BitAndAssign for Bounded<$type:ty>) => {
+        $crate::bit_and_assign!($type);
+    };
+    (BitOr for Bounded<$type:ty>) => {
+        $crate::bit_or!($type);
+    };
+    (BitOrAssign for Bounded<$type:ty>) => {
+        $crate::bit_or_assign!($type);
+    };
+    (BitXor for Bounded<$type:ty>) => {
+        $crate::bit_xor!($type);
+    };
+    (BitXorAssign for Bounded<$type:ty>) => {
+        $crate::bit_xor_assign!($type);
+    };
+    (Debug for Bounded<$type:ty>) => {
+        $crate::debug!($type);
+    };
+    (Default for Bounded<$type:ty>) => {
+        $crate::default!($type);
+    };
     (From for Bounded<$type:ty>) => {
         $crate::from!($type);
     };
@@ -28,6 +75,9 @@ macro_rules! r#impl {
     (Neg for Bounded<$type:ty>) => {
         $crate::neg!($type);
     };
+    (Not for Bounded<$type:ty>) => {
+        $crate::not!($type);
+    };
     (RangeBounds for Bounded<$type:ty>) => {
         $crate::range_bounds!($type);
     };
@@ -101,7 +151,7 @@ macro_rules! r#impl {
     };
     // Extra traits
     (Bounded for Bounded<$type:ty>) => {
-        $crate::bounded!($type);
+        $crate::num_bounded!($type);
     };
     (CheckedAdd for Bounded<$type:ty>) => {
         $crate::checked_add!($type);
@@ -127,6 +177,21 @@ macro_rules! r#impl {
     (CheckedSub for Bounded<$type:ty>) => {
         $crate::checked_sub!($type);
     };
+    (FromPrimitive for Bounded<$type:ty>) => {
+        $crate::from_primitive!($type);
+    };
+    (Num for Bounded<$type:ty>) => {
+        $crate::num!($type);
+    };
+    (NumCast for Bounded<$type:ty>) => {
+        $crate::num_cast!($type);
+    };
+    (One for Bounded<$type:ty>) => {
+        $crate::one!($type);
+    };
+    (Distribution for Bounded<$type:ty>) => {
+        $crate::distribution!($type);
+    };
     (SaturatingAdd for Bounded<$type:ty>) => {
         $crate::saturating_add!($type);
     };
@@ -145,24 +210,71 @@ macro_rules! r#impl {
     (SaturatingSub for Bounded<$type:ty>) => {
         $crate::saturating_sub!($type);
     };
+    (SampleUniform for Bounded<$type:ty>) => {
+        $crate::sample_uniform!($type);
+    };
+    (ToPrimitive for Bounded<$type:ty>) => {
+        $crate::to_primitive!($type);
+    };
+    (Zero for Bounded<$type:ty>) => {
+        $crate::zero!($type);
+    };
+    // Foreign traits
+    (arbitrary::Arbitrary for Bounded<$type:ty>) => {
+        $crate::arbitrary!($type);
+    };
+    (bytemuck::CheckedBitPattern for Bounded<$type:ty>) => {
+        $crate::checked_bit_pattern!($type);
+    };
+    (bytemuck::NoUninit for Bounded<$type:ty>) => {
+        $crate::no_uninit!($type);
+    };
+    (bytemuck::Zeroable for Bounded<$type:ty>) => {
+        $crate::zeroable!($type);
+    };
+    (proptest::Arbitrary for Bounded<$type:ty>) => {
+        $crate::proptest_arbitrary!($type);
+    };
+    (schemars::JsonSchema for Bounded<$type:ty>) => {
+        $crate::json_schema!($type);
+    };
     (Bounded<$($type:ty),+>) => {
         $(r#impl!(Bounded<$type>);)+
     };
     ($trait:ident for Bounded<$($type:ty),+>) => {
         $(r#impl!($trait for Bounded<$type>);)+
     };
+    ($krate:ident::$trait:ident for Bounded<$($type:ty),+>) => {
+        $(r#impl!($krate::$trait for Bounded<$type>);)+
+    };
+    ($trait:ident for Bounded<$($type:ty => $unsigned:ty),+>) => {
+        $(r#impl!($trait for Bounded<$type => $unsigned>);)+
+    };
 }
 
 #[macro_export]
 macro_rules! new {
     ($type:ty) => {
-        impl<const MIN: i128, const MAX: u128> $crate::Bounded<$type, MIN, MAX> {
+        impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+            /// Fails to compile when the bounds are out of the range of the
+            /// type or `MIN` is greater than `MAX`, `MIN == MAX` is allowed
+            ///
+            /// `u128` bounds above `i128::MAX` are written as `u128 as i128`,
+            /// so every `i128` is in the range of `u128`.
+            const VALID: () = {
+                assert!(
+                    MIN as $type as i128 == MIN,
+                    "MIN is out of the range of the type"
+                );
+                assert!(
+                    MAX as $type as i128 == MAX,
+                    "MAX is out of the range of the type"
+                );
+                assert!(MIN as $type <= MAX as $type, "MIN is greater than MAX");
+            };
+
             pub const fn checked_new(value: $type) -> Option<Self> {
-                if MIN >= 0 {
-                    assert!(MAX > MIN as u128);
-                }
-                assert!(MIN >= <$type>::MIN as i128);
-                assert!(MAX <= <$type>::MAX as u128);
+                let () = Self::VALID;
                 if MIN as $type <= value && value <= MAX as $type {
                     Some(Self(value))
                 } else {
@@ -176,526 +288,2684 @@ macro_rules! new {
                     None => panic!("value is out of bounds"),
                 }
             }
+
+            /// Like `new`, but the panic message names the value and the
+            /// bounds, see `bounded!`
+            #[doc(hidden)]
+            pub const fn from_literal(value: $type) -> Self {
+                match Self::checked_new(value) {
+                    Some(val) => val,
+                    None => {
+                        let message = $crate::literal::Message::new(
+                            Self::sign_magnitude(value),
+                            Self::sign_magnitude(MIN as $type),
+                            Self::sign_magnitude(MAX as $type),
+                        );
+                        panic!("{}", message.as_str())
+                    }
+                }
+            }
+
+            const fn sign_magnitude(value: $type) -> (bool, u128) {
+                if <$type>::MIN != 0 && (value as i128) < 0 {
+                    (true, (value as i128).unsigned_abs())
+                } else {
+                    (false, value as u128)
+                }
+            }
+
+            pub const fn get(self) -> $type {
+                self.0
+            }
+
+            /// Sets the value, returning `None` and leaving it unchanged when
+            /// it is out of bounds
+            pub const fn checked_set(&mut self, value: $type) -> Option<()> {
+                match Self::checked_new(value) {
+                    Some(val) => {
+                        *self = val;
+                        Some(())
+                    }
+                    None => None,
+                }
+            }
+
+            pub const fn set(&mut self, value: $type) {
+                *self = Self::new(value);
+            }
         }
     };
 }
 
-mod traits {
+/// Creates a bounded integer, checking the bounds at compile time
+///
+/// An out of bounds value fails to compile with a message naming the value
+/// and the bounds.
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use bounded::{bounded, Bounded};
+///
+/// type Digit = Bounded<u8, 0, 9>;
+///
+/// assert_eq!(Digit::new(7), bounded!(Digit, 7));
+/// ```
+///
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use bounded::{bounded, Bounded};
+///
+/// type Digit = Bounded<u8, 0, 9>;
+///
+/// let _ = bounded!(Digit, 10);
+/// ```
+#[macro_export]
+macro_rules! bounded {
+    ($type:ty, $value:expr) => {{
+        const VALUE: $type = <$type>::from_literal($value);
+        VALUE
+    }};
+}
+
+mod methods {
     #[macro_export]
-    macro_rules! add {
+    macro_rules! arithmetic {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::Add
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                type Output = Self;
+            impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+                /// Adds `rhs`, panicking when the result is out of bounds
+                ///
+                /// Unlike the operator, it can be used in a const context.
+                pub const fn const_add(self, rhs: Self) -> Self {
+                    match Self::checked_new(self.0 + rhs.0) {
+                        Some(value) => value,
+                        None => panic!("attempt to add with out of bounds"),
+                    }
+                }
 
-                fn add(self, rhs: Self) -> Self::Output {
-                    self + rhs.0
+                /// Subtracts `rhs`, panicking when the result is out of bounds
+                ///
+                /// Unlike the operator, it can be used in a const context.
+                pub const fn const_sub(self, rhs: Self) -> Self {
+                    match Self::checked_new(self.0 - rhs.0) {
+                        Some(value) => value,
+                        None => panic!("attempt to subtract with out of bounds"),
+                    }
                 }
-            }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::Add<$type>
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                type Output = Self;
+                /// Multiplies by `rhs`, panicking when the result is out of bounds
+                ///
+                /// Unlike the operator, it can be used in a const context.
+                pub const fn const_mul(self, rhs: Self) -> Self {
+                    match Self::checked_new(self.0 * rhs.0) {
+                        Some(value) => value,
+                        None => panic!("attempt to multiply with out of bounds"),
+                    }
+                }
 
-                fn add(self, rhs: $type) -> Self::Output {
-                    Self::checked_new(self.0 + rhs).expect("attempt to add with out of bounds")
+                /// Divides by `rhs`, panicking when the result is out of bounds
+                ///
+                /// Unlike the operator, it can be used in a const context.
+                pub const fn const_div(self, rhs: Self) -> Self {
+                    match Self::checked_new(self.0 / rhs.0) {
+                        Some(value) => value,
+                        None => panic!("attempt to divide with out of bounds"),
+                    }
+                }
+
+                /// Calculates the remainder of division by `rhs`, panicking when the result is out of bounds
+                ///
+                /// Unlike the operator, it can be used in a const context.
+                pub const fn const_rem(self, rhs: Self) -> Self {
+                    match Self::checked_new(self.0 % rhs.0) {
+                        Some(value) => value,
+                        None => panic!("attempt to calculate the remainder with out of bounds"),
+                    }
+                }
+
+                /// Adds `rhs`, returning `None` if overflow or out of bounds
+                /// occurred
+                ///
+                /// Prefixed with `const_` since `checked_add` would shadow
+                /// `CheckedAdd::checked_add` and the one of the inner integer,
+                /// which is reached through `Deref`.
+                pub const fn const_checked_add(self, rhs: Self) -> Option<Self> {
+                    match self.0.checked_add(rhs.0) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Subtracts `rhs`, returning `None` if overflow or out of bounds
+                /// occurred
+                ///
+                /// Prefixed with `const_` since `checked_sub` would shadow
+                /// `CheckedSub::checked_sub` and the one of the inner integer,
+                /// which is reached through `Deref`.
+                pub const fn const_checked_sub(self, rhs: Self) -> Option<Self> {
+                    match self.0.checked_sub(rhs.0) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Multiplies by `rhs`, returning `None` if overflow or out of bounds
+                /// occurred
+                ///
+                /// Prefixed with `const_` since `checked_mul` would shadow
+                /// `CheckedMul::checked_mul` and the one of the inner integer,
+                /// which is reached through `Deref`.
+                pub const fn const_checked_mul(self, rhs: Self) -> Option<Self> {
+                    match self.0.checked_mul(rhs.0) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Divides by `rhs`, returning `None` if overflow or out of bounds
+                /// occurred
+                ///
+                /// Prefixed with `const_` since `checked_div` would shadow
+                /// `CheckedDiv::checked_div` and the one of the inner integer,
+                /// which is reached through `Deref`.
+                pub const fn const_checked_div(self, rhs: Self) -> Option<Self> {
+                    match self.0.checked_div(rhs.0) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Calculates the remainder of division by `rhs`, returning `None` if overflow or out of bounds
+                /// occurred
+                ///
+                /// Prefixed with `const_` since `checked_rem` would shadow
+                /// `CheckedRem::checked_rem` and the one of the inner integer,
+                /// which is reached through `Deref`.
+                pub const fn const_checked_rem(self, rhs: Self) -> Option<Self> {
+                    match self.0.checked_rem(rhs.0) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Adds `rhs`, saturating at the bounds
+                ///
+                /// Prefixed with `const_` since `saturating_add` would shadow
+                /// `SaturatingAdd::saturating_add` and the one of the inner
+                /// integer, which is reached through `Deref`.
+                pub const fn const_saturating_add(self, rhs: Self) -> Self {
+                    Self::saturate(self.0.saturating_add(rhs.0))
+                }
+
+                /// Subtracts `rhs`, saturating at the bounds
+                ///
+                /// Prefixed with `const_` since `saturating_sub` would shadow
+                /// `SaturatingSub::saturating_sub` and the one of the inner
+                /// integer, which is reached through `Deref`.
+                pub const fn const_saturating_sub(self, rhs: Self) -> Self {
+                    Self::saturate(self.0.saturating_sub(rhs.0))
+                }
+
+                /// Multiplies by `rhs`, saturating at the bounds
+                ///
+                /// Prefixed with `const_` since `saturating_mul` would shadow
+                /// `SaturatingMul::saturating_mul` and the one of the inner
+                /// integer, which is reached through `Deref`.
+                pub const fn const_saturating_mul(self, rhs: Self) -> Self {
+                    Self::saturate(self.0.saturating_mul(rhs.0))
+                }
+
+                /// Divides by `rhs`, saturating at the bounds
+                ///
+                /// Prefixed with `const_` since `saturating_div` would shadow
+                /// `SaturatingDiv::saturating_div` and the one of the inner
+                /// integer, which is reached through `Deref`.
+                pub const fn const_saturating_div(self, rhs: Self) -> Self {
+                    Self::saturate(self.0.saturating_div(rhs.0))
+                }
+
+                /// Adds `rhs`, wrapping around the bounds
+                ///
+                /// Prefixed with `const_` since `wrapping_add` would shadow the
+                /// one of the inner integer, which is reached through `Deref`.
+                pub const fn const_wrapping_add(self, rhs: Self) -> Self {
+                    let m = $crate::modular::modulus(MIN, MAX);
+                    let residue = $crate::modular::add(self.residue(m), rhs.residue(m), m);
+                    Self($crate::modular::wrap(residue, MIN, m) as $type)
+                }
+
+                /// Subtracts `rhs`, wrapping around the bounds
+                ///
+                /// Prefixed with `const_` since `wrapping_sub` would shadow the
+                /// one of the inner integer, which is reached through `Deref`.
+                pub const fn const_wrapping_sub(self, rhs: Self) -> Self {
+                    let m = $crate::modular::modulus(MIN, MAX);
+                    let residue = $crate::modular::sub(self.residue(m), rhs.residue(m), m);
+                    Self($crate::modular::wrap(residue, MIN, m) as $type)
+                }
+
+                /// Multiplies by `rhs`, wrapping around the bounds
+                ///
+                /// Prefixed with `const_` since `wrapping_mul` would shadow the
+                /// one of the inner integer, which is reached through `Deref`.
+                pub const fn const_wrapping_mul(self, rhs: Self) -> Self {
+                    let m = $crate::modular::modulus(MIN, MAX);
+                    let residue = $crate::modular::mul(self.residue(m), rhs.residue(m), m);
+                    Self($crate::modular::wrap(residue, MIN, m) as $type)
+                }
+
+                const fn saturate(value: $type) -> Self {
+                    if value < MIN as $type {
+                        Self(MIN as $type)
+                    } else if value > MAX as $type {
+                        Self(MAX as $type)
+                    } else {
+                        Self(value)
+                    }
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! add_assign {
-        ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::AddAssign
-                for $crate::Bounded<$type, MIN, MAX>
+    macro_rules! atomic {
+        ($type:ty, $atomic:ty) => {
+            impl $crate::atomic::Sealed for $type {}
+
+            impl $crate::AtomicPrimitive for $type {
+                type Atomic = $atomic;
+            }
+
+            impl<const MIN: i128, const MAX: i128> $crate::AtomicBounded<$type, MIN, MAX> {
+                /// Creates a new atomic bounded integer
+                pub const fn new(value: $crate::Bounded<$type, MIN, MAX>) -> Self {
+                    Self(<$atomic>::new(value.0))
+                }
+
+                /// Consumes the atomic and returns the contained value
+                pub fn into_inner(self) -> $crate::Bounded<$type, MIN, MAX> {
+                    $crate::Bounded(self.0.into_inner())
+                }
+
+                /// Loads a value from the atomic
+                pub fn load(
+                    &self,
+                    order: core::sync::atomic::Ordering,
+                ) -> $crate::Bounded<$type, MIN, MAX> {
+                    $crate::Bounded(self.0.load(order))
+                }
+
+                /// Stores a value into the atomic
+                pub fn store(
+                    &self,
+                    value: $crate::Bounded<$type, MIN, MAX>,
+                    order: core::sync::atomic::Ordering,
+                ) {
+                    self.0.store(value.0, order);
+                }
+
+                /// Stores a value into the atomic, returning the previous value
+                pub fn swap(
+                    &self,
+                    value: $crate::Bounded<$type, MIN, MAX>,
+                    order: core::sync::atomic::Ordering,
+                ) -> $crate::Bounded<$type, MIN, MAX> {
+                    $crate::Bounded(self.0.swap(value.0, order))
+                }
+
+                /// Stores `new` into the atomic if the current value is the
+                /// same as `current`
+                ///
+                /// The return value is a result indicating whether the new
+                /// value was written and containing the previous value.
+                pub fn compare_exchange(
+                    &self,
+                    current: $crate::Bounded<$type, MIN, MAX>,
+                    new: $crate::Bounded<$type, MIN, MAX>,
+                    success: core::sync::atomic::Ordering,
+                    failure: core::sync::atomic::Ordering,
+                ) -> Result<$crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, MIN, MAX>>
+                {
+                    self.0
+                        .compare_exchange(current.0, new.0, success, failure)
+                        .map($crate::Bounded)
+                        .map_err($crate::Bounded)
+                }
+
+                /// Adds to the current value, unless the result is out of
+                /// bounds
+                ///
+                /// Returns `Ok` with the previous value if it was updated and
+                /// `Err` with the current value otherwise.
+                pub fn checked_fetch_add(
+                    &self,
+                    value: $type,
+                    order: core::sync::atomic::Ordering,
+                ) -> Result<$crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, MIN, MAX>>
+                {
+                    self.fetch_update(order, |current| {
+                        $crate::Bounded::<$type, MIN, MAX>::checked_new(current.checked_add(value)?)
+                    })
+                }
+
+                /// Subtracts from the current value, unless the result is out
+                /// of bounds
+                ///
+                /// Returns `Ok` with the previous value if it was updated and
+                /// `Err` with the current value otherwise.
+                pub fn checked_fetch_sub(
+                    &self,
+                    value: $type,
+                    order: core::sync::atomic::Ordering,
+                ) -> Result<$crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, MIN, MAX>>
+                {
+                    self.fetch_update(order, |current| {
+                        $crate::Bounded::<$type, MIN, MAX>::checked_new(current.checked_sub(value)?)
+                    })
+                }
+
+                /// Adds to the current value, saturating at the bounds, and
+                /// returns the previous value
+                pub fn saturating_fetch_add(
+                    &self,
+                    value: $type,
+                    order: core::sync::atomic::Ordering,
+                ) -> $crate::Bounded<$type, MIN, MAX> {
+                    self.saturating_fetch_update(order, |current| current.saturating_add(value))
+                }
+
+                /// Subtracts from the current value, saturating at the bounds,
+                /// and returns the previous value
+                pub fn saturating_fetch_sub(
+                    &self,
+                    value: $type,
+                    order: core::sync::atomic::Ordering,
+                ) -> $crate::Bounded<$type, MIN, MAX> {
+                    self.saturating_fetch_update(order, |current| current.saturating_sub(value))
+                }
+
+                fn fetch_update(
+                    &self,
+                    order: core::sync::atomic::Ordering,
+                    mut f: impl FnMut($type) -> Option<$crate::Bounded<$type, MIN, MAX>>,
+                ) -> Result<$crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, MIN, MAX>>
+                {
+                    self.0
+                        .fetch_update(order, $crate::atomic::failure_ordering(order), |current| {
+                            Some(f(current)?.0)
+                        })
+                        .map($crate::Bounded)
+                        .map_err($crate::Bounded)
+                }
+
+                fn saturating_fetch_update(
+                    &self,
+                    order: core::sync::atomic::Ordering,
+                    mut f: impl FnMut($type) -> $type,
+                ) -> $crate::Bounded<$type, MIN, MAX> {
+                    let previous = self.fetch_update(order, |current| {
+                        Some($crate::Bounded(
+                            f(current).clamp(MIN as $type, MAX as $type),
+                        ))
+                    });
+                    previous.unwrap_or_else(|previous| previous)
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::fmt::Debug
+                for $crate::AtomicBounded<$type, MIN, MAX>
             {
-                fn add_assign(&mut self, rhs: Self) {
-                    *self += rhs.0;
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    core::fmt::Debug::fmt(&self.load(core::sync::atomic::Ordering::Relaxed), f)
                 }
             }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::AddAssign<$type>
-                for $crate::Bounded<$type, MIN, MAX>
+            impl<const MIN: i128, const MAX: i128> From<$crate::Bounded<$type, MIN, MAX>>
+                for $crate::AtomicBounded<$type, MIN, MAX>
             {
-                fn add_assign(&mut self, rhs: $type) {
-                    *self = *self + rhs;
+                fn from(from: $crate::Bounded<$type, MIN, MAX>) -> Self {
+                    Self::new(from)
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! div {
-        ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::Div
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                type Output = Self;
-
-                fn div(self, rhs: Self) -> Self::Output {
-                    self / rhs.0
+    macro_rules! abs_diff {
+        ($type:ty, $unsigned:ty) => {
+            impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+                /// Computes the absolute difference between self and `other`,
+                /// bounded by the width of the range
+                pub fn abs_diff(
+                    self,
+                    other: Self,
+                ) -> $crate::Bounded<$unsigned, 0, { MAX.wrapping_sub(MIN) }> {
+                    $crate::Bounded(self.0.abs_diff(other.0))
                 }
             }
+        };
+    }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::Div<$type>
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                type Output = Self;
+    #[macro_export]
+    macro_rules! euclid {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+                /// Calculates the quotient of Euclidean division of self by
+                /// `rhs`
+                pub fn div_euclid<const RHS_MIN: i128, const RHS_MAX: i128>(
+                    self,
+                    rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>,
+                ) -> Self {
+                    Self::checked_new(self.0.div_euclid(rhs.0))
+                        .expect("attempt to divide with out of bounds")
+                }
 
-                fn div(self, rhs: $type) -> Self::Output {
-                    Self::checked_new(self.0 / rhs).expect("attempt to divide with out of bounds")
+                /// Calculates the least nonnegative remainder of self modulo
+                /// positive `rhs`, bounded by the greatest divisor
+                pub fn rem_euclid<const RHS_MIN: i128, const RHS_MAX: i128>(
+                    self,
+                    rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>,
+                ) -> $crate::Bounded<$type, 0, { RHS_MAX.wrapping_sub(1) }> {
+                    let () = $crate::assert::Divisor::<RHS_MIN, { <$type>::MIN == 0 }>::POSITIVE;
+                    $crate::Bounded(self.0.rem_euclid(rhs.0))
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! div_assign {
+    macro_rules! ilog {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::DivAssign
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                fn div_assign(&mut self, rhs: Self) {
-                    *self /= rhs.0;
+            impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+                /// Returns the base 2 logarithm of the number, rounded down
+                ///
+                /// The logarithm never exceeds the number itself, so only a
+                /// positive `MIN` is checked.
+                pub fn ilog2(self) -> Self {
+                    let value = self.0.ilog2() as $type;
+                    if (MIN as $type) < 1 {
+                        Self(value)
+                    } else {
+                        Self::checked_new(value)
+                            .expect("attempt to calculate the logarithm with out of bounds")
+                    }
                 }
-            }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::DivAssign<$type>
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                fn div_assign(&mut self, rhs: $type) {
-                    *self = *self / rhs;
+                /// Returns the base 10 logarithm of the number, rounded down
+                ///
+                /// The logarithm never exceeds the number itself, so only a
+                /// positive `MIN` is checked.
+                pub fn ilog10(self) -> Self {
+                    let value = self.0.ilog10() as $type;
+                    if (MIN as $type) < 1 {
+                        Self(value)
+                    } else {
+                        Self::checked_new(value)
+                            .expect("attempt to calculate the logarithm with out of bounds")
+                    }
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! from {
+    macro_rules! isqrt {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> From<$type>
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                fn from(from: $type) -> Self {
-                    Self::new(from)
+            impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+                /// Returns the square root of the number, rounded down
+                ///
+                /// The square root never exceeds the number itself, so only a
+                /// `MIN` greater than 1 is checked.
+                pub fn isqrt(self) -> Self {
+                    let value = self.0.isqrt();
+                    if MIN as $type <= 1 {
+                        Self(value)
+                    } else {
+                        Self::checked_new(value)
+                            .expect("attempt to calculate the square root with out of bounds")
+                    }
                 }
             }
+        };
+    }
 
-            impl<const MIN: i128, const MAX: u128> From<$crate::Bounded<$type, MIN, MAX>>
-                for $type
-            {
-                fn from(from: $crate::Bounded<$type, MIN, MAX>) -> Self {
-                    from.0
+    #[macro_export]
+    macro_rules! sign {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+                /// Computes the absolute value of self
+                pub fn abs(self) -> Self {
+                    Self::checked_new(self.0.abs())
+                        .expect("attempt to calculate the absolute value with out of bounds")
+                }
+
+                /// Returns a number representing sign of self
+                pub fn signum(self) -> $crate::Bounded<i8, -1, 1> {
+                    $crate::Bounded(self.0.signum() as i8)
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! mul {
+    macro_rules! pow {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::Mul
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                type Output = Self;
+            impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+                /// Raises self to the power of `exp`
+                pub fn pow(self, exp: u32) -> Self {
+                    Self::checked_new(self.0.pow(exp))
+                        .expect("attempt to calculate the power with out of bounds")
+                }
 
-                fn mul(self, rhs: Self) -> Self::Output {
-                    self * rhs.0
+                /// Raises self to the power of `exp`, returning `None` if
+                /// overflow or out of bounds occurred
+                pub fn checked_pow(self, exp: u32) -> Option<Self> {
+                    Self::checked_new(self.0.checked_pow(exp)?)
                 }
-            }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::Mul<$type>
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                type Output = Self;
+                /// Raises self to the power of `exp`, saturating at the bounds
+                pub fn saturating_pow(self, exp: u32) -> Self {
+                    Self::saturate(self.0.saturating_pow(exp))
+                }
 
-                fn mul(self, rhs: $type) -> Self::Output {
-                    Self::checked_new(self.0 * rhs).expect("attempt to multiply with out of bounds")
+                /// Raises self to the power of `exp`, wrapping around the
+                /// bounds
+                pub fn wrapping_pow(self, exp: u32) -> Self {
+                    let m = $crate::modular::modulus(MIN, MAX);
+                    let power = $crate::modular::pow(self.residue(m), exp, m);
+                    Self($crate::modular::wrap(power, MIN, m) as $type)
+                }
+
+                const fn residue(self, m: u128) -> u128 {
+                    if <$type>::MIN == 0 {
+                        $crate::modular::from_unsigned(self.0 as u128, m)
+                    } else {
+                        $crate::modular::from_signed(self.0 as i128, m)
+                    }
                 }
             }
         };
     }
+}
 
+mod map {
     #[macro_export]
-    macro_rules! mul_assign {
+    macro_rules! key {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::MulAssign
+            impl<const MIN: i128, const MAX: i128> $crate::map::Sealed
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn mul_assign(&mut self, rhs: Self) {
-                    *self *= rhs.0;
-                }
             }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::MulAssign<$type>
+            impl<const MIN: i128, const MAX: i128> $crate::map::Key
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn mul_assign(&mut self, rhs: $type) {
-                    *self = *self * rhs;
+                const COUNT: usize = {
+                    let () = Self::VALID;
+                    let count = $crate::modular::modulus(MIN, MAX);
+                    assert!(
+                        count != 0 && count <= usize::MAX as u128,
+                        "count of keys exceeds usize"
+                    );
+                    count as usize
+                };
+
+                fn to_index(self) -> usize {
+                    (self.0 as u128).wrapping_sub(MIN as u128) as usize
+                }
+
+                fn from_index(index: usize) -> Self {
+                    assert!(index < Self::COUNT, "index is out of bounds");
+                    Self((MIN as u128).wrapping_add(index as u128) as $type)
                 }
             }
         };
     }
+}
 
+mod traits {
     #[macro_export]
-    macro_rules! neg {
-        ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::Neg
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                type Output = Self;
+    macro_rules! forward_ref_binop {
+        (impl $imp:ident, $method:ident for $lhs:ty, $rhs:ty) => {
+            $crate::forward_ref_binop!(
+                impl<const MIN: i128, const MAX: i128> $imp, $method for $lhs, $rhs
+            );
+        };
+        (
+            impl<$(const $param:ident: $type:ty),+> $imp:ident, $method:ident for $lhs:ty, $rhs:ty
+        ) => {
+            impl<$(const $param: $type),+> core::ops::$imp<$rhs> for &$lhs {
+                type Output = <$lhs as core::ops::$imp<$rhs>>::Output;
 
-                fn neg(self) -> Self::Output {
-                    Self::checked_new(-self.0).expect("attempt to negate with out of bounds")
+                fn $method(self, rhs: $rhs) -> Self::Output {
+                    core::ops::$imp::$method(*self, rhs)
+                }
+            }
+
+            impl<$(const $param: $type),+> core::ops::$imp<&$rhs> for $lhs {
+                type Output = <$lhs as core::ops::$imp<$rhs>>::Output;
+
+                fn $method(self, rhs: &$rhs) -> Self::Output {
+                    core::ops::$imp::$method(self, *rhs)
+                }
+            }
+
+            impl<$(const $param: $type),+> core::ops::$imp<&$rhs> for &$lhs {
+                type Output = <$lhs as core::ops::$imp<$rhs>>::Output;
+
+                fn $method(self, rhs: &$rhs) -> Self::Output {
+                    core::ops::$imp::$method(*self, *rhs)
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! range_bounds {
-        ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::RangeBounds<$type>
-                for $crate::Bounded<$type, MIN, MAX>
-            {
-                fn start_bound(&self) -> core::ops::Bound<&$type> {
-                    core::ops::Bound::Included(&(MIN as $type))
+    macro_rules! forward_ref_op_assign {
+        (impl $imp:ident, $method:ident for $lhs:ty, $rhs:ty) => {
+            $crate::forward_ref_op_assign!(
+                impl<const MIN: i128, const MAX: i128> $imp, $method for $lhs, $rhs
+            );
+        };
+        (
+            impl<$(const $param:ident: $type:ty),+> $imp:ident, $method:ident for $lhs:ty, $rhs:ty
+        ) => {
+            impl<$(const $param: $type),+> core::ops::$imp<&$rhs> for $lhs {
+                fn $method(&mut self, rhs: &$rhs) {
+                    core::ops::$imp::$method(self, *rhs);
                 }
+            }
+        };
+    }
 
-                fn end_bound(&self) -> core::ops::Bound<&$type> {
-                    core::ops::Bound::Included(&(MAX as $type))
+    #[macro_export]
+    macro_rules! forward_ref_unop {
+        (impl $imp:ident, $method:ident for $type:ty) => {
+            impl<const MIN: i128, const MAX: i128> core::ops::$imp for &$type {
+                type Output = <$type as core::ops::$imp>::Output;
+
+                fn $method(self) -> Self::Output {
+                    core::ops::$imp::$method(*self)
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! rem {
+    macro_rules! add {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::Rem
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::Add<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
                 type Output = Self;
 
-                fn rem(self, rhs: Self) -> Self::Output {
-                    self % rhs.0
+                fn add(self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) -> Self::Output {
+                    self + rhs.0
                 }
             }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::Rem<$type>
+            impl<const MIN: i128, const MAX: i128> core::ops::Add<$type>
                 for $crate::Bounded<$type, MIN, MAX>
             {
                 type Output = Self;
 
-                fn rem(self, rhs: $type) -> Self::Output {
-                    Self::checked_new(self.0 % rhs)
-                        .expect("attempt to calculate the remainder with out of bounds")
+                fn add(self, rhs: $type) -> Self::Output {
+                    Self::checked_new(self.0 + rhs).expect("attempt to add with out of bounds")
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::Add<$crate::Bounded<$type, MIN, MAX>> for $type
+            {
+                type Output = $crate::Bounded<$type, MIN, MAX>;
+
+                fn add(self, rhs: $crate::Bounded<$type, MIN, MAX>) -> Self::Output {
+                    rhs + self
                 }
             }
+
+            $crate::forward_ref_binop!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                Add, add
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_binop!(impl Add, add for $crate::Bounded<$type, MIN, MAX>, $type);
+            $crate::forward_ref_binop!(impl Add, add for $type, $crate::Bounded<$type, MIN, MAX>);
         };
     }
 
     #[macro_export]
-    macro_rules! rem_assign {
+    macro_rules! add_assign {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::RemAssign
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::AddAssign<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn rem_assign(&mut self, rhs: Self) {
-                    *self %= rhs.0;
+                fn add_assign(&mut self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) {
+                    *self += rhs.0;
                 }
             }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::RemAssign<$type>
+            impl<const MIN: i128, const MAX: i128> core::ops::AddAssign<$type>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn rem_assign(&mut self, rhs: $type) {
-                    *self = *self % rhs;
+                fn add_assign(&mut self, rhs: $type) {
+                    *self = *self + rhs;
                 }
             }
+
+            $crate::forward_ref_op_assign!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                AddAssign, add_assign
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_op_assign!(
+                impl AddAssign, add_assign for $crate::Bounded<$type, MIN, MAX>, $type
+            );
         };
     }
 
     #[macro_export]
-    macro_rules! shl {
-        ($lhs:ty, $rhs:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::Shl<$rhs>
-                for $crate::Bounded<$lhs, MIN, MAX>
+    macro_rules! bit_and {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::BitAnd<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
+                for $crate::Bounded<$type, MIN, MAX>
             {
                 type Output = Self;
 
-                fn shl(self, rhs: $rhs) -> Self::Output {
-                    Self::checked_new(self.0 << rhs)
-                        .expect("attempt to shift left with out of bounds")
+                fn bitand(self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) -> Self::Output {
+                    self & rhs.0
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::BitAnd<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Output = Self;
+
+                fn bitand(self, rhs: $type) -> Self::Output {
+                    let value = self.0 & rhs;
+                    // `x & y <= x` for unsigned integers, so the result can't be out of
+                    // bounds when `MIN` is 0
+                    if <$type>::MIN == 0 && MIN == 0 {
+                        Self(value)
+                    } else {
+                        Self::checked_new(value)
+                            .expect("attempt to perform bitwise and with out of bounds")
+                    }
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::BitAnd<$crate::Bounded<$type, MIN, MAX>> for $type
+            {
+                type Output = $crate::Bounded<$type, MIN, MAX>;
+
+                fn bitand(self, rhs: $crate::Bounded<$type, MIN, MAX>) -> Self::Output {
+                    rhs & self
                 }
             }
+
+            $crate::forward_ref_binop!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                BitAnd, bitand
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_binop!(
+                impl BitAnd, bitand for $crate::Bounded<$type, MIN, MAX>, $type
+            );
+            $crate::forward_ref_binop!(
+                impl BitAnd, bitand for $type, $crate::Bounded<$type, MIN, MAX>
+            );
         };
     }
 
     #[macro_export]
-    macro_rules! shl_assign {
-        ($lhs:ty, $rhs:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::ShlAssign<$rhs>
-                for $crate::Bounded<$lhs, MIN, MAX>
+    macro_rules! bit_and_assign {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::BitAndAssign<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
+                for $crate::Bounded<$type, MIN, MAX>
             {
-                fn shl_assign(&mut self, rhs: $rhs) {
-                    *self = *self << rhs;
+                fn bitand_assign(&mut self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) {
+                    *self &= rhs.0;
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::BitAndAssign<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn bitand_assign(&mut self, rhs: $type) {
+                    *self = *self & rhs;
                 }
             }
+
+            $crate::forward_ref_op_assign!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                BitAndAssign, bitand_assign
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_op_assign!(
+                impl BitAndAssign, bitand_assign for $crate::Bounded<$type, MIN, MAX>, $type
+            );
         };
     }
 
     #[macro_export]
-    macro_rules! shr {
-        ($lhs:ty, $rhs:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::Shr<$rhs>
-                for $crate::Bounded<$lhs, MIN, MAX>
+    macro_rules! bit_or {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::BitOr<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
+                for $crate::Bounded<$type, MIN, MAX>
             {
                 type Output = Self;
 
-                fn shr(self, rhs: $rhs) -> Self::Output {
-                    Self::checked_new(self.0 >> rhs)
-                        .expect("attempt to shift right with out of bounds")
+                fn bitor(self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) -> Self::Output {
+                    self | rhs.0
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::BitOr<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Output = Self;
+
+                fn bitor(self, rhs: $type) -> Self::Output {
+                    Self::checked_new(self.0 | rhs)
+                        .expect("attempt to perform bitwise or with out of bounds")
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::BitOr<$crate::Bounded<$type, MIN, MAX>> for $type
+            {
+                type Output = $crate::Bounded<$type, MIN, MAX>;
+
+                fn bitor(self, rhs: $crate::Bounded<$type, MIN, MAX>) -> Self::Output {
+                    rhs | self
                 }
             }
+
+            $crate::forward_ref_binop!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                BitOr, bitor
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_binop!(
+                impl BitOr, bitor for $crate::Bounded<$type, MIN, MAX>, $type
+            );
+            $crate::forward_ref_binop!(
+                impl BitOr, bitor for $type, $crate::Bounded<$type, MIN, MAX>
+            );
         };
     }
 
     #[macro_export]
-    macro_rules! shr_assign {
-        ($lhs:ty, $rhs:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::ShrAssign<$rhs>
-                for $crate::Bounded<$lhs, MIN, MAX>
+    macro_rules! bit_or_assign {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::BitOrAssign<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
+                for $crate::Bounded<$type, MIN, MAX>
             {
-                fn shr_assign(&mut self, rhs: $rhs) {
-                    *self = *self >> rhs;
+                fn bitor_assign(&mut self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) {
+                    *self |= rhs.0;
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::BitOrAssign<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn bitor_assign(&mut self, rhs: $type) {
+                    *self = *self | rhs;
                 }
             }
+
+            $crate::forward_ref_op_assign!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                BitOrAssign, bitor_assign
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_op_assign!(
+                impl BitOrAssign, bitor_assign for $crate::Bounded<$type, MIN, MAX>, $type
+            );
         };
     }
 
     #[macro_export]
-    macro_rules! sub {
+    macro_rules! bit_xor {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::Sub
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::BitXor<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
                 type Output = Self;
 
-                fn sub(self, rhs: Self) -> Self::Output {
-                    self - rhs.0
+                fn bitxor(self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) -> Self::Output {
+                    self ^ rhs.0
                 }
             }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::Sub<$type>
+            impl<const MIN: i128, const MAX: i128> core::ops::BitXor<$type>
                 for $crate::Bounded<$type, MIN, MAX>
             {
                 type Output = Self;
 
-                fn sub(self, rhs: $type) -> Self::Output {
-                    Self::checked_new(self.0 - rhs).expect("attempt to subtract with out of bounds")
+                fn bitxor(self, rhs: $type) -> Self::Output {
+                    Self::checked_new(self.0 ^ rhs)
+                        .expect("attempt to perform bitwise xor with out of bounds")
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::BitXor<$crate::Bounded<$type, MIN, MAX>> for $type
+            {
+                type Output = $crate::Bounded<$type, MIN, MAX>;
+
+                fn bitxor(self, rhs: $crate::Bounded<$type, MIN, MAX>) -> Self::Output {
+                    rhs ^ self
                 }
             }
+
+            $crate::forward_ref_binop!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                BitXor, bitxor
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_binop!(
+                impl BitXor, bitxor for $crate::Bounded<$type, MIN, MAX>, $type
+            );
+            $crate::forward_ref_binop!(
+                impl BitXor, bitxor for $type, $crate::Bounded<$type, MIN, MAX>
+            );
         };
     }
 
     #[macro_export]
-    macro_rules! sub_assign {
+    macro_rules! bit_xor_assign {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> core::ops::SubAssign
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::BitXorAssign<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn sub_assign(&mut self, rhs: Self) {
-                    *self -= rhs.0;
+                fn bitxor_assign(&mut self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) {
+                    *self ^= rhs.0;
                 }
             }
 
-            impl<const MIN: i128, const MAX: u128> core::ops::SubAssign<$type>
+            impl<const MIN: i128, const MAX: i128> core::ops::BitXorAssign<$type>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn sub_assign(&mut self, rhs: $type) {
-                    *self = *self - rhs;
+                fn bitxor_assign(&mut self, rhs: $type) {
+                    *self = *self ^ rhs;
                 }
             }
+
+            $crate::forward_ref_op_assign!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                BitXorAssign, bitxor_assign
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_op_assign!(
+                impl BitXorAssign, bitxor_assign for $crate::Bounded<$type, MIN, MAX>, $type
+            );
         };
     }
-}
 
-#[cfg(feature = "extra-traits")]
-mod extra_traits {
+    /// The alternate format also prints the bounds, cast to the type
     #[macro_export]
-    macro_rules! bounded {
+    macro_rules! debug {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::Bounded
+            impl<const MIN: i128, const MAX: i128> core::fmt::Debug
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn min_value() -> Self {
-                    Self(MIN as $type)
-                }
-
-                fn max_value() -> Self {
-                    Self(MAX as $type)
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    if f.alternate() {
+                        f.write_str("Bounded(")?;
+                        core::fmt::Debug::fmt(&self.0, f)?;
+                        write!(f, " in {}..={})", MIN as $type, MAX as $type)
+                    } else {
+                        f.debug_tuple("Bounded").field(&self.0).finish()
+                    }
                 }
             }
         };
     }
 
+    /// Implemented only when 0 is in bounds, so that the default is never
+    /// out of bounds
     #[macro_export]
-    macro_rules! checked_add {
+    macro_rules! default {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::CheckedAdd
-                for $crate::Bounded<$type, MIN, MAX>
+            impl<const MIN: i128, const MAX: i128> Default for $crate::Bounded<$type, MIN, MAX>
+            where
+                $crate::Assert<{ $crate::contains_zero(MIN, MAX, <$type>::MIN == 0) }>:
+                    $crate::IsTrue,
             {
-                fn checked_add(&self, rhs: &Self) -> Option<Self> {
-                    Self::checked_new(self.0.checked_add(rhs.0)?)
+                fn default() -> Self {
+                    let () = Self::VALID;
+                    Self(0)
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! checked_div {
+    macro_rules! div {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::CheckedDiv
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::Div<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn checked_div(&self, rhs: &Self) -> Option<Self> {
-                    Self::checked_new(self.0.checked_div(rhs.0)?)
+                type Output = Self;
+
+                fn div(self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) -> Self::Output {
+                    self / rhs.0
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::Div<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Output = Self;
+
+                fn div(self, rhs: $type) -> Self::Output {
+                    Self::checked_new(self.0 / rhs).expect("attempt to divide with out of bounds")
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::Div<$crate::Bounded<$type, MIN, MAX>> for $type
+            {
+                type Output = $crate::Bounded<$type, MIN, MAX>;
+
+                fn div(self, rhs: $crate::Bounded<$type, MIN, MAX>) -> Self::Output {
+                    Self::Output::checked_new(self / rhs.0)
+                        .expect("attempt to divide with out of bounds")
                 }
             }
+
+            $crate::forward_ref_binop!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                Div, div
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_binop!(impl Div, div for $crate::Bounded<$type, MIN, MAX>, $type);
+            $crate::forward_ref_binop!(impl Div, div for $type, $crate::Bounded<$type, MIN, MAX>);
         };
     }
 
     #[macro_export]
-    macro_rules! checked_mul {
+    macro_rules! div_assign {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::CheckedMul
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::DivAssign<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
-                    Self::checked_new(self.0.checked_mul(rhs.0)?)
+                fn div_assign(&mut self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) {
+                    *self /= rhs.0;
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::DivAssign<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn div_assign(&mut self, rhs: $type) {
+                    *self = *self / rhs;
                 }
             }
+
+            $crate::forward_ref_op_assign!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                DivAssign, div_assign
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_op_assign!(
+                impl DivAssign, div_assign for $crate::Bounded<$type, MIN, MAX>, $type
+            );
         };
     }
 
     #[macro_export]
-    macro_rules! checked_neg {
+    macro_rules! from {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::CheckedNeg
+            impl<const MIN: i128, const MAX: i128> From<$type>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn checked_neg(&self) -> Option<Self> {
-                    Self::checked_new(self.0.checked_neg()?)
+                fn from(from: $type) -> Self {
+                    Self::new(from)
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> From<$crate::Bounded<$type, MIN, MAX>>
+                for $type
+            {
+                fn from(from: $crate::Bounded<$type, MIN, MAX>) -> Self {
+                    from.0
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! checked_rem {
+    macro_rules! mul {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::CheckedRem
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::Mul<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn checked_rem(&self, rhs: &Self) -> Option<Self> {
-                    Self::checked_new(self.0.checked_rem(rhs.0)?)
+                type Output = Self;
+
+                fn mul(self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) -> Self::Output {
+                    self * rhs.0
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::Mul<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Output = Self;
+
+                fn mul(self, rhs: $type) -> Self::Output {
+                    Self::checked_new(self.0 * rhs).expect("attempt to multiply with out of bounds")
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::Mul<$crate::Bounded<$type, MIN, MAX>> for $type
+            {
+                type Output = $crate::Bounded<$type, MIN, MAX>;
+
+                fn mul(self, rhs: $crate::Bounded<$type, MIN, MAX>) -> Self::Output {
+                    rhs * self
                 }
             }
+
+            $crate::forward_ref_binop!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                Mul, mul
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_binop!(impl Mul, mul for $crate::Bounded<$type, MIN, MAX>, $type);
+            $crate::forward_ref_binop!(impl Mul, mul for $type, $crate::Bounded<$type, MIN, MAX>);
         };
     }
 
     #[macro_export]
-    macro_rules! checked_shl {
+    macro_rules! mul_assign {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::CheckedShl
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::MulAssign<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn checked_shl(&self, rhs: u32) -> Option<Self> {
-                    Self::checked_new(self.0.checked_shl(rhs)?)
+                fn mul_assign(&mut self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) {
+                    *self *= rhs.0;
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::MulAssign<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn mul_assign(&mut self, rhs: $type) {
+                    *self = *self * rhs;
                 }
             }
+
+            $crate::forward_ref_op_assign!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                MulAssign, mul_assign
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_op_assign!(
+                impl MulAssign, mul_assign for $crate::Bounded<$type, MIN, MAX>, $type
+            );
         };
     }
 
     #[macro_export]
-    macro_rules! checked_shr {
+    macro_rules! neg {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::CheckedShr
+            impl<const MIN: i128, const MAX: i128> core::ops::Neg
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn checked_shr(&self, rhs: u32) -> Option<Self> {
-                    Self::checked_new(self.0.checked_shr(rhs)?)
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self::checked_new(-self.0).expect("attempt to negate with out of bounds")
                 }
             }
+
+            $crate::forward_ref_unop!(impl Neg, neg for $crate::Bounded<$type, MIN, MAX>);
         };
     }
 
     #[macro_export]
-    macro_rules! checked_sub {
+    macro_rules! not {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::CheckedSub
+            impl<const MIN: i128, const MAX: i128> core::ops::Not
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
-                    Self::checked_new(self.0.checked_sub(rhs.0)?)
+                type Output = Self;
+
+                fn not(self) -> Self::Output {
+                    Self::checked_new(!self.0)
+                        .expect("attempt to perform bitwise not with out of bounds")
                 }
             }
+
+            $crate::forward_ref_unop!(impl Not, not for $crate::Bounded<$type, MIN, MAX>);
         };
     }
 
     #[macro_export]
-    macro_rules! saturating_add {
+    macro_rules! range_bounds {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::SaturatingAdd
+            impl<const MIN: i128, const MAX: i128> core::ops::RangeBounds<$type>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn saturating_add(&self, rhs: &Self) -> Self {
-                    Self::checked_new(self.0.saturating_add(rhs.0)).unwrap_or(Self(MAX as $type))
+                fn start_bound(&self) -> core::ops::Bound<&$type> {
+                    core::ops::Bound::Included(&(MIN as $type))
+                }
+
+                fn end_bound(&self) -> core::ops::Bound<&$type> {
+                    core::ops::Bound::Included(&(MAX as $type))
                 }
             }
         };
     }
 
     #[macro_export]
-    macro_rules! saturating_div {
+    macro_rules! rem {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> $crate::SaturatingDiv
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::Rem<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn saturating_div(&self, rhs: &Self) -> Self {
-                    Self::checked_new(self.0 / rhs.0).unwrap_or(Self(MIN as $type))
+                type Output = Self;
+
+                fn rem(self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) -> Self::Output {
+                    self % rhs.0
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::Rem<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Output = Self;
+
+                fn rem(self, rhs: $type) -> Self::Output {
+                    Self::checked_new(self.0 % rhs)
+                        .expect("attempt to calculate the remainder with out of bounds")
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::Rem<$crate::Bounded<$type, MIN, MAX>> for $type
+            {
+                type Output = $crate::Bounded<$type, MIN, MAX>;
+
+                fn rem(self, rhs: $crate::Bounded<$type, MIN, MAX>) -> Self::Output {
+                    Self::Output::checked_new(self % rhs.0)
+                        .expect("attempt to calculate the remainder with out of bounds")
                 }
             }
+
+            $crate::forward_ref_binop!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                Rem, rem
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_binop!(impl Rem, rem for $crate::Bounded<$type, MIN, MAX>, $type);
+            $crate::forward_ref_binop!(impl Rem, rem for $type, $crate::Bounded<$type, MIN, MAX>);
         };
     }
 
     #[macro_export]
-    macro_rules! saturating_mul {
+    macro_rules! rem_assign {
         ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::SaturatingMul
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::RemAssign<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
                 for $crate::Bounded<$type, MIN, MAX>
             {
-                fn saturating_mul(&self, rhs: &Self) -> Self {
-                    Self::checked_new(self.0.saturating_mul(rhs.0)).unwrap_or(Self(MAX as $type))
+                fn rem_assign(&mut self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) {
+                    *self %= rhs.0;
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::RemAssign<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn rem_assign(&mut self, rhs: $type) {
+                    *self = *self % rhs;
                 }
             }
+
+            $crate::forward_ref_op_assign!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                RemAssign, rem_assign
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_op_assign!(
+                impl RemAssign, rem_assign for $crate::Bounded<$type, MIN, MAX>, $type
+            );
         };
     }
 
     #[macro_export]
-    macro_rules! saturating_sub {
-        ($type:ty) => {
-            impl<const MIN: i128, const MAX: u128> num_traits::SaturatingSub
-                for $crate::Bounded<$type, MIN, MAX>
+    macro_rules! shl {
+        ($lhs:ty, $rhs:ty) => {
+            impl<const MIN: i128, const MAX: i128> core::ops::Shl<$rhs>
+                for $crate::Bounded<$lhs, MIN, MAX>
             {
-                fn saturating_sub(&self, rhs: &Self) -> Self {
-                    Self::checked_new(self.0.saturating_sub(rhs.0)).unwrap_or(Self(MIN as $type))
+                type Output = Self;
+
+                fn shl(self, rhs: $rhs) -> Self::Output {
+                    Self::checked_new(self.0 << rhs)
+                        .expect("attempt to shift left with out of bounds")
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::Shl<$crate::Bounded<$rhs, MIN, MAX>> for $lhs
+            {
+                type Output = $lhs;
+
+                fn shl(self, rhs: $crate::Bounded<$rhs, MIN, MAX>) -> Self::Output {
+                    self << rhs.0
                 }
             }
+
+            $crate::forward_ref_binop!(impl Shl, shl for $crate::Bounded<$lhs, MIN, MAX>, $rhs);
+            $crate::forward_ref_binop!(impl Shl, shl for $lhs, $crate::Bounded<$rhs, MIN, MAX>);
         };
     }
+
+    #[macro_export]
+    macro_rules! shl_assign {
+        ($lhs:ty, $rhs:ty) => {
+            impl<const MIN: i128, const MAX: i128> core::ops::ShlAssign<$rhs>
+                for $crate::Bounded<$lhs, MIN, MAX>
+            {
+                fn shl_assign(&mut self, rhs: $rhs) {
+                    *self = *self << rhs;
+                }
+            }
+
+            $crate::forward_ref_op_assign!(
+                impl ShlAssign, shl_assign for $crate::Bounded<$lhs, MIN, MAX>, $rhs
+            );
+        };
+    }
+
+    #[macro_export]
+    macro_rules! shr {
+        ($lhs:ty, $rhs:ty) => {
+            impl<const MIN: i128, const MAX: i128> core::ops::Shr<$rhs>
+                for $crate::Bounded<$lhs, MIN, MAX>
+            {
+                type Output = Self;
+
+                fn shr(self, rhs: $rhs) -> Self::Output {
+                    Self::checked_new(self.0 >> rhs)
+                        .expect("attempt to shift right with out of bounds")
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::Shr<$crate::Bounded<$rhs, MIN, MAX>> for $lhs
+            {
+                type Output = $lhs;
+
+                fn shr(self, rhs: $crate::Bounded<$rhs, MIN, MAX>) -> Self::Output {
+                    self >> rhs.0
+                }
+            }
+
+            $crate::forward_ref_binop!(impl Shr, shr for $crate::Bounded<$lhs, MIN, MAX>, $rhs);
+            $crate::forward_ref_binop!(impl Shr, shr for $lhs, $crate::Bounded<$rhs, MIN, MAX>);
+        };
+    }
+
+    #[macro_export]
+    macro_rules! shr_assign {
+        ($lhs:ty, $rhs:ty) => {
+            impl<const MIN: i128, const MAX: i128> core::ops::ShrAssign<$rhs>
+                for $crate::Bounded<$lhs, MIN, MAX>
+            {
+                fn shr_assign(&mut self, rhs: $rhs) {
+                    *self = *self >> rhs;
+                }
+            }
+
+            $crate::forward_ref_op_assign!(
+                impl ShrAssign, shr_assign for $crate::Bounded<$lhs, MIN, MAX>, $rhs
+            );
+        };
+    }
+
+    #[macro_export]
+    macro_rules! sub {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::Sub<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Output = Self;
+
+                fn sub(self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) -> Self::Output {
+                    self - rhs.0
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::Sub<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Output = Self;
+
+                fn sub(self, rhs: $type) -> Self::Output {
+                    Self::checked_new(self.0 - rhs).expect("attempt to subtract with out of bounds")
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128>
+                core::ops::Sub<$crate::Bounded<$type, MIN, MAX>> for $type
+            {
+                type Output = $crate::Bounded<$type, MIN, MAX>;
+
+                fn sub(self, rhs: $crate::Bounded<$type, MIN, MAX>) -> Self::Output {
+                    Self::Output::checked_new(self - rhs.0)
+                        .expect("attempt to subtract with out of bounds")
+                }
+            }
+
+            $crate::forward_ref_binop!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                Sub, sub
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_binop!(impl Sub, sub for $crate::Bounded<$type, MIN, MAX>, $type);
+            $crate::forward_ref_binop!(impl Sub, sub for $type, $crate::Bounded<$type, MIN, MAX>);
+        };
+    }
+
+    #[macro_export]
+    macro_rules! sub_assign {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                core::ops::SubAssign<$crate::Bounded<$type, RHS_MIN, RHS_MAX>>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn sub_assign(&mut self, rhs: $crate::Bounded<$type, RHS_MIN, RHS_MAX>) {
+                    *self -= rhs.0;
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::ops::SubAssign<$type>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn sub_assign(&mut self, rhs: $type) {
+                    *self = *self - rhs;
+                }
+            }
+
+            $crate::forward_ref_op_assign!(
+                impl<const MIN: i128, const MAX: i128, const RHS_MIN: i128, const RHS_MAX: i128>
+                SubAssign, sub_assign
+                for $crate::Bounded<$type, MIN, MAX>, $crate::Bounded<$type, RHS_MIN, RHS_MAX>
+            );
+            $crate::forward_ref_op_assign!(
+                impl SubAssign, sub_assign for $crate::Bounded<$type, MIN, MAX>, $type
+            );
+        };
+    }
+}
+
+#[cfg(feature = "num-traits")]
+mod extra_traits {
+    #[macro_export]
+    macro_rules! num_bounded {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::Bounded
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn min_value() -> Self {
+                    let () = Self::VALID;
+                    Self(MIN as $type)
+                }
+
+                fn max_value() -> Self {
+                    let () = Self::VALID;
+                    Self(MAX as $type)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! checked_add {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::CheckedAdd
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    Self::checked_new(self.0.checked_add(rhs.0)?)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! checked_div {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::CheckedDiv
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                    Self::checked_new(self.0.checked_div(rhs.0)?)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! checked_mul {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::CheckedMul
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    Self::checked_new(self.0.checked_mul(rhs.0)?)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! checked_neg {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::CheckedNeg
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn checked_neg(&self) -> Option<Self> {
+                    Self::checked_new(self.0.checked_neg()?)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! checked_rem {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::CheckedRem
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+                    Self::checked_new(self.0.checked_rem(rhs.0)?)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! checked_shl {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::CheckedShl
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn checked_shl(&self, rhs: u32) -> Option<Self> {
+                    Self::checked_new(self.0.checked_shl(rhs)?)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! checked_shr {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::CheckedShr
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn checked_shr(&self, rhs: u32) -> Option<Self> {
+                    Self::checked_new(self.0.checked_shr(rhs)?)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! checked_sub {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::CheckedSub
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    Self::checked_new(self.0.checked_sub(rhs.0)?)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! from_primitive {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::FromPrimitive
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn from_i64(n: i64) -> Option<Self> {
+                    Self::checked_new(<$type as num_traits::FromPrimitive>::from_i64(n)?)
+                }
+
+                fn from_i128(n: i128) -> Option<Self> {
+                    Self::checked_new(<$type as num_traits::FromPrimitive>::from_i128(n)?)
+                }
+
+                fn from_u64(n: u64) -> Option<Self> {
+                    Self::checked_new(<$type as num_traits::FromPrimitive>::from_u64(n)?)
+                }
+
+                fn from_u128(n: u128) -> Option<Self> {
+                    Self::checked_new(<$type as num_traits::FromPrimitive>::from_u128(n)?)
+                }
+            }
+        };
+    }
+
+    /// Implemented only when 0 and 1 are in bounds, like `Zero` and `One`
+    #[macro_export]
+    macro_rules! num {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::Num
+                for $crate::Bounded<$type, MIN, MAX>
+            where
+                $crate::Assert<{ $crate::contains_zero(MIN, MAX, <$type>::MIN == 0) }>:
+                    $crate::IsTrue,
+                $crate::Assert<{ $crate::contains_one(MIN, MAX, <$type>::MIN == 0) }>:
+                    $crate::IsTrue,
+            {
+                type FromStrRadixErr = $crate::ParseError;
+
+                fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                    Self::checked_new(<$type>::from_str_radix(str, radix)?)
+                        .ok_or($crate::ParseError::OutOfBounds)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! num_cast {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::NumCast
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+                    Self::checked_new(<$type as num_traits::NumCast>::from(n)?)
+                }
+            }
+        };
+    }
+
+    /// Implemented only when 1 is in bounds
+    ///
+    #[cfg_attr(feature = "one", doc = "```compile_fail")]
+    #[cfg_attr(not(feature = "one"), doc = "```ignore")]
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use bounded::num_traits::One;
+    ///
+    /// let _ = bounded::Bounded::<u8, 2, 9>::one();
+    /// ```
+    #[macro_export]
+    macro_rules! one {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::One
+                for $crate::Bounded<$type, MIN, MAX>
+            where
+                $crate::Assert<{ $crate::contains_one(MIN, MAX, <$type>::MIN == 0) }>:
+                    $crate::IsTrue,
+            {
+                fn one() -> Self {
+                    let () = Self::VALID;
+                    Self(1)
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! saturating_add {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::SaturatingAdd
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn saturating_add(&self, rhs: &Self) -> Self {
+                    Self::saturate(self.0.saturating_add(rhs.0))
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! saturating_div {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> $crate::SaturatingDiv
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn saturating_div(&self, rhs: &Self) -> Self {
+                    Self::saturate(self.0.saturating_div(rhs.0))
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! saturating_mul {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::SaturatingMul
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn saturating_mul(&self, rhs: &Self) -> Self {
+                    Self::saturate(self.0.saturating_mul(rhs.0))
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! saturating_sub {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::SaturatingSub
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn saturating_sub(&self, rhs: &Self) -> Self {
+                    Self::saturate(self.0.saturating_sub(rhs.0))
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! to_primitive {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::ToPrimitive
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn to_i64(&self) -> Option<i64> {
+                    num_traits::ToPrimitive::to_i64(&self.0)
+                }
+
+                fn to_i128(&self) -> Option<i128> {
+                    num_traits::ToPrimitive::to_i128(&self.0)
+                }
+
+                fn to_u64(&self) -> Option<u64> {
+                    num_traits::ToPrimitive::to_u64(&self.0)
+                }
+
+                fn to_u128(&self) -> Option<u128> {
+                    num_traits::ToPrimitive::to_u128(&self.0)
+                }
+            }
+        };
+    }
+
+    /// Implemented only when 0 is in bounds
+    ///
+    #[cfg_attr(feature = "zero", doc = "```compile_fail")]
+    #[cfg_attr(not(feature = "zero"), doc = "```ignore")]
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use bounded::num_traits::Zero;
+    ///
+    /// let _ = bounded::Bounded::<u8, 1, 9>::zero();
+    /// ```
+    #[macro_export]
+    macro_rules! zero {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> num_traits::Zero
+                for $crate::Bounded<$type, MIN, MAX>
+            where
+                $crate::Assert<{ $crate::contains_zero(MIN, MAX, <$type>::MIN == 0) }>:
+                    $crate::IsTrue,
+            {
+                fn zero() -> Self {
+                    let () = Self::VALID;
+                    Self(0)
+                }
+
+                fn is_zero(&self) -> bool {
+                    self.0 == 0
+                }
+            }
+        };
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand {
+    #[macro_export]
+    macro_rules! distribution {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128>
+                rand::distributions::Distribution<$crate::Bounded<$type, MIN, MAX>>
+                for rand::distributions::Standard
+            {
+                fn sample<R: rand::Rng + ?Sized>(
+                    &self,
+                    rng: &mut R,
+                ) -> $crate::Bounded<$type, MIN, MAX> {
+                    let () = $crate::Bounded::<$type, MIN, MAX>::VALID;
+                    $crate::Bounded(rng.gen_range(MIN as $type..=MAX as $type))
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! sample_uniform {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> rand::distributions::uniform::SampleUniform
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Sampler = $crate::UniformBounded<$type, MIN, MAX>;
+            }
+
+            impl<const MIN: i128, const MAX: i128> rand::distributions::uniform::UniformSampler
+                for $crate::UniformBounded<$type, MIN, MAX>
+            {
+                type X = $crate::Bounded<$type, MIN, MAX>;
+
+                fn new<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                    B2: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                {
+                    Self(rand::distributions::uniform::UniformInt::<$type>::new(
+                        low.borrow().0,
+                        high.borrow().0,
+                    ))
+                }
+
+                fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+                where
+                    B1: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                    B2: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                {
+                    Self(
+                        rand::distributions::uniform::UniformInt::<$type>::new_inclusive(
+                            low.borrow().0,
+                            high.borrow().0,
+                        ),
+                    )
+                }
+
+                fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                    $crate::Bounded(self.0.sample(rng))
+                }
+            }
+        };
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary {
+    /// Maps the fuzzer bytes onto the bounds without rejection, so an empty
+    /// input yields `MIN`
+    #[macro_export]
+    macro_rules! arbitrary {
+        ($type:ty) => {
+            impl<'a, const MIN: i128, const MAX: i128> arbitrary::Arbitrary<'a>
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                    let () = Self::VALID;
+                    Ok(Self(u.int_in_range(MIN as $type..=MAX as $type)?))
+                }
+
+                fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                    (0, Some(core::mem::size_of::<$type>()))
+                }
+            }
+        };
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck {
+    #[macro_export]
+    macro_rules! checked_bit_pattern {
+        ($type:ty) => {
+            // SAFETY: `Bounded` is `repr(transparent)` over `$type`
+            unsafe impl<const MIN: i128, const MAX: i128> bytemuck::CheckedBitPattern
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Bits = $type;
+
+                fn is_valid_bit_pattern(bits: &$type) -> bool {
+                    Self::checked_new(*bits).is_some()
+                }
+            }
+        };
+    }
+
+    #[macro_export]
+    macro_rules! no_uninit {
+        ($type:ty) => {
+            // SAFETY: `Bounded` is `repr(transparent)` over `$type`, which has
+            // no padding
+            unsafe impl<const MIN: i128, const MAX: i128> bytemuck::NoUninit
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+            }
+        };
+    }
+
+    /// Implemented only when 0 is in bounds
+    #[macro_export]
+    macro_rules! zeroable {
+        ($type:ty) => {
+            // SAFETY: `Bounded` is `repr(transparent)` over `$type` and the
+            // all-zero bit pattern is 0, which is in bounds
+            unsafe impl<const MIN: i128, const MAX: i128> bytemuck::Zeroable
+                for $crate::Bounded<$type, MIN, MAX>
+            where
+                $crate::Assert<{ $crate::contains_zero(MIN, MAX, <$type>::MIN == 0) }>:
+                    $crate::IsTrue,
+            {
+            }
+        };
+    }
+}
+
+#[cfg(feature = "clap")]
+mod clap {
+    #[macro_export]
+    macro_rules! value_parser_factory {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> clap::builder::ValueParserFactory
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Parser = $crate::BoundedValueParser<$type, MIN, MAX>;
+
+                fn value_parser() -> Self::Parser {
+                    $crate::BoundedValueParser::default()
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> $crate::BoundedValueParser<$type, MIN, MAX> {
+                /// Returns the values in bounds when there are few enough to be
+                /// listed in the help
+                fn listed_values(&self) -> Option<core::ops::RangeInclusive<$type>> {
+                    let count = $crate::modular::modulus(MIN, MAX);
+                    if count == 0 || count > 16 {
+                        return None;
+                    }
+                    Some(MIN as $type..=MAX as $type)
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> clap::builder::TypedValueParser
+                for $crate::BoundedValueParser<$type, MIN, MAX>
+            {
+                type Value = $crate::Bounded<$type, MIN, MAX>;
+
+                fn parse_ref(
+                    &self,
+                    cmd: &clap::Command,
+                    arg: Option<&clap::Arg>,
+                    value: &std::ffi::OsStr,
+                ) -> Result<Self::Value, clap::Error> {
+                    use clap::error::{ContextKind, ContextValue, ErrorKind};
+
+                    let value = value
+                        .to_str()
+                        .ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+                    let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+                    let invalid = |reason: &dyn core::fmt::Display| {
+                        let mut error = clap::Error::raw(
+                            ErrorKind::ValueValidation,
+                            format!("invalid value '{}' for '{}': {}\n", value, arg, reason),
+                        )
+                        .with_cmd(cmd);
+                        error.insert(ContextKind::InvalidArg, ContextValue::String(arg.clone()));
+                        error.insert(
+                            ContextKind::InvalidValue,
+                            ContextValue::String(value.to_owned()),
+                        );
+                        let range = format!("expected a value in {}", self);
+                        error.insert(
+                            ContextKind::Suggested,
+                            ContextValue::StyledStrs(vec![range.into()]),
+                        );
+                        error
+                    };
+                    let parsed = value.parse::<$type>().map_err(|error| invalid(&error))?;
+                    <$crate::Bounded<$type, MIN, MAX>>::checked_new(parsed).ok_or_else(|| {
+                        invalid(&format_args!(
+                            "{} is not in {}..={}",
+                            parsed, MIN as $type, MAX as $type
+                        ))
+                    })
+                }
+
+                /// Lists the values in bounds when there are at most 16 of them,
+                /// larger ranges are only named in the errors
+                fn possible_values(
+                    &self,
+                ) -> Option<Box<dyn Iterator<Item = clap::builder::PossibleValue> + '_>> {
+                    let values = self.listed_values()?;
+                    Some(Box::new(values.map(|value| {
+                        clap::builder::PossibleValue::new(value.to_string())
+                    })))
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> core::fmt::Display
+                for $crate::BoundedValueParser<$type, MIN, MAX>
+            {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    write!(f, "{}..={}", MIN as $type, MAX as $type)
+                }
+            }
+        };
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest {
+    /// Shrinks toward 0 when it is in bounds and toward `MIN` otherwise
+    #[macro_export]
+    macro_rules! proptest_arbitrary {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> proptest::arbitrary::Arbitrary
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                type Parameters = ();
+                type Strategy =
+                    proptest::strategy::Map<core::ops::RangeInclusive<$type>, fn($type) -> Self>;
+
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    let () = Self::VALID;
+                    let (min, max) = (MIN as $type, MAX as $type);
+                    // A range shrinks toward its bound nearest to 0, which is
+                    // `MAX` for a negative one, so the offset from `MIN` is
+                    // generated instead
+                    if <$type>::MIN != 0 && MAX < 0 {
+                        proptest::strategy::Strategy::prop_map(0..=max - min, |offset| {
+                            Self(MIN as $type + offset)
+                        })
+                    } else {
+                        proptest::strategy::Strategy::prop_map(min..=max, Self)
+                    }
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+                /// Returns a strategy generating only the edge values: `MIN`,
+                /// `MIN + 1`, `MAX - 1` and `MAX`
+                pub fn edge_strategy() -> impl proptest::strategy::Strategy<Value = Self> {
+                    let () = Self::VALID;
+                    let (min, max) = (MIN as $type, MAX as $type);
+                    proptest::sample::select(vec![
+                        Self(min),
+                        Self(min.saturating_add(1).min(max)),
+                        Self(max.saturating_sub(1).max(min)),
+                        Self(max),
+                    ])
+                }
+
+                /// Returns a strategy generating an edge value half of the
+                /// time and any value otherwise
+                pub fn edge_biased_strategy() -> impl proptest::strategy::Strategy<Value = Self> {
+                    proptest::prop_oneof![
+                        Self::edge_strategy(),
+                        proptest::arbitrary::any::<Self>(),
+                    ]
+                }
+            }
+        };
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars {
+    /// Extends the schema of the primitive with the bounds, leaving out a
+    /// bound JSON can't represent exactly, i.e. beyond `i64` and `u64`
+    #[macro_export]
+    macro_rules! json_schema {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> schemars::JsonSchema
+                for $crate::Bounded<$type, MIN, MAX>
+            {
+                fn schema_name() -> std::borrow::Cow<'static, str> {
+                    format!(
+                        "Bounded_{}_{}_{}",
+                        stringify!($type),
+                        MIN as $type,
+                        MAX as $type
+                    )
+                    .into()
+                }
+
+                fn schema_id() -> std::borrow::Cow<'static, str> {
+                    format!(
+                        "bounded::Bounded<{}, {}, {}>",
+                        stringify!($type),
+                        MIN as $type,
+                        MAX as $type
+                    )
+                    .into()
+                }
+
+                fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                    let mut schema = <$type as schemars::JsonSchema>::json_schema(generator);
+                    schema.remove("minimum");
+                    schema.remove("maximum");
+                    if let Ok(minimum) = serde_json::to_value(MIN as $type) {
+                        schema.insert("minimum".to_owned(), minimum);
+                    }
+                    if let Ok(maximum) = serde_json::to_value(MAX as $type) {
+                        schema.insert("maximum".to_owned(), maximum);
+                    }
+                    schema
+                }
+            }
+        };
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy {
+    /// zerocopy's `TryFromBytes` can only be derived and can't check the
+    /// bounds of a tuple field, so the checked casts are inherent methods
+    /// built on the `FromBytes` of the inner type
+    #[macro_export]
+    macro_rules! try_ref_from_bytes {
+        ($type:ty) => {
+            impl<const MIN: i128, const MAX: i128> $crate::Bounded<$type, MIN, MAX> {
+                /// Interprets the bytes as a bounded integer, returning `None`
+                /// if they are misaligned, of the wrong size or out of bounds
+                pub fn try_ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+                    let value = <$type as zerocopy::FromBytes>::ref_from_bytes(bytes).ok()?;
+                    Self::checked_new(*value)?;
+                    // SAFETY: `Bounded` is `repr(transparent)` over `$type` and
+                    // the value is in bounds
+                    Some(unsafe { &*(value as *const $type as *const Self) })
+                }
+
+                /// Interprets the bytes as a slice of bounded integers,
+                /// returning `None` if they are misaligned, of the wrong size
+                /// or any of them is out of bounds
+                pub fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+                    let values = <[$type] as zerocopy::FromBytes>::ref_from_bytes(bytes).ok()?;
+                    if values
+                        .iter()
+                        .any(|&value| Self::checked_new(value).is_none())
+                    {
+                        return None;
+                    }
+                    // SAFETY: `Bounded` is `repr(transparent)` over `$type` and
+                    // all the values are in bounds
+                    Some(unsafe { &*(values as *const [$type] as *const [Self]) })
+                }
+            }
+        };
+    }
+}
+
+/// Every forwarded impl is bounded by `for<'a> $inner: Trait` so that it is
+/// silently left out when the inner type does not implement the trait
+#[cfg(feature = "derive")]
+mod newtype {
+    /// Implements the traits of `BoundedNewtype`
+    ///
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// #[derive(bounded::BoundedNewtype, Clone, Copy, Debug, PartialEq)]
+    /// #[bounded(deref)]
+    /// struct Percent(bounded::Bounded<u8, 0, 100>);
+    ///
+    /// type Digit = bounded::BoundedI8<-9, 9>;
+    ///
+    /// #[derive(bounded::BoundedNewtype, Clone, Copy, Debug, PartialEq)]
+    /// #[bounded(primitive = i8)]
+    /// struct Level(Digit);
+    ///
+    /// assert_eq!(Percent::from(30), 10 + Percent::from(20));
+    /// assert_eq!(30, Percent::from(30).get());
+    /// assert_eq!(Level::from(-9), -Level::from(9));
+    /// ```
+    ///
+    /// An unsigned newtype doesn't implement `Neg`, and one without `deref`
+    /// doesn't implement `Deref`:
+    ///
+    /// ```compile_fail
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// #[derive(bounded::BoundedNewtype)]
+    /// struct Percent(bounded::Bounded<u8, 0, 100>);
+    ///
+    /// let _ = -Percent::from(1);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// #[derive(bounded::BoundedNewtype)]
+    /// struct Percent(bounded::Bounded<u8, 0, 100>);
+    ///
+    /// let _ = Percent::from(1).get();
+    /// ```
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype {
+        ($name:ident, $inner:ty, $primitive:ty) => {
+            impl From<$inner> for $name {
+                fn from(from: $inner) -> Self {
+                    Self(from)
+                }
+            }
+
+            impl From<$name> for $inner {
+                fn from(from: $name) -> Self {
+                    from.0
+                }
+            }
+
+            impl From<$primitive> for $name
+            where
+                for<'a> $inner: From<$primitive>,
+            {
+                fn from(from: $primitive) -> Self {
+                    Self(<$inner>::from(from))
+                }
+            }
+
+            impl From<$name> for $primitive {
+                fn from(from: $name) -> Self {
+                    *from.0
+                }
+            }
+
+            $crate::newtype_fmt!($name, $inner, Binary);
+            $crate::newtype_fmt!($name, $inner, Display);
+            $crate::newtype_fmt!($name, $inner, LowerExp);
+            $crate::newtype_fmt!($name, $inner, LowerHex);
+            $crate::newtype_fmt!($name, $inner, Octal);
+            $crate::newtype_fmt!($name, $inner, UpperExp);
+            $crate::newtype_fmt!($name, $inner, UpperHex);
+
+            $crate::newtype_binop!($name, $inner, $primitive, Add, add, AddAssign, add_assign);
+            $crate::newtype_binop!(
+                $name,
+                $inner,
+                $primitive,
+                BitAnd,
+                bitand,
+                BitAndAssign,
+                bitand_assign
+            );
+            $crate::newtype_binop!(
+                $name,
+                $inner,
+                $primitive,
+                BitOr,
+                bitor,
+                BitOrAssign,
+                bitor_assign
+            );
+            $crate::newtype_binop!(
+                $name,
+                $inner,
+                $primitive,
+                BitXor,
+                bitxor,
+                BitXorAssign,
+                bitxor_assign
+            );
+            $crate::newtype_binop!($name, $inner, $primitive, Div, div, DivAssign, div_assign);
+            $crate::newtype_binop!($name, $inner, $primitive, Mul, mul, MulAssign, mul_assign);
+            $crate::newtype_binop!($name, $inner, $primitive, Rem, rem, RemAssign, rem_assign);
+            $crate::newtype_binop!($name, $inner, $primitive, Sub, sub, SubAssign, sub_assign);
+            $crate::newtype_shift!($name, $inner, Shl, shl, ShlAssign, shl_assign);
+            $crate::newtype_shift!($name, $inner, Shr, shr, ShrAssign, shr_assign);
+            $crate::newtype_unop!($name, $inner, Neg, neg);
+            $crate::newtype_unop!($name, $inner, Not, not);
+
+            $crate::newtype_num_traits!($name, $inner);
+            $crate::newtype_saturating_div!($name, $inner);
+        };
+        ($name:ident, $inner:ty, $primitive:ty, deref) => {
+            $crate::newtype!($name, $inner, $primitive);
+
+            impl core::ops::Deref for $name {
+                type Target = $inner;
+
+                fn deref(&self) -> &$inner {
+                    &self.0
+                }
+            }
+        };
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_fmt {
+        ($name:ident, $inner:ty, $trait:ident) => {
+            impl core::fmt::$trait for $name
+            where
+                for<'a> $inner: core::fmt::$trait,
+            {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    core::fmt::$trait::fmt(&self.0, f)
+                }
+            }
+        };
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_binop {
+        ($name:ident, $inner:ty, $primitive:ty, $trait:ident, $method:ident, $assign:ident, $assign_method:ident) => {
+            impl core::ops::$trait for $name
+            where
+                for<'a> $inner: core::ops::$trait<Output = $inner>,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    Self(core::ops::$trait::$method(self.0, rhs.0))
+                }
+            }
+
+            impl core::ops::$trait<$primitive> for $name
+            where
+                for<'a> $inner: core::ops::$trait<$primitive, Output = $inner>,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: $primitive) -> Self::Output {
+                    Self(core::ops::$trait::$method(self.0, rhs))
+                }
+            }
+
+            impl core::ops::$trait<$name> for $primitive
+            where
+                for<'a> $primitive: core::ops::$trait<$inner, Output = $inner>,
+            {
+                type Output = $name;
+
+                fn $method(self, rhs: $name) -> Self::Output {
+                    $name(core::ops::$trait::$method(self, rhs.0))
+                }
+            }
+
+            impl core::ops::$assign for $name
+            where
+                for<'a> $inner: core::ops::$assign,
+            {
+                fn $assign_method(&mut self, rhs: Self) {
+                    core::ops::$assign::$assign_method(&mut self.0, rhs.0)
+                }
+            }
+
+            impl core::ops::$assign<$primitive> for $name
+            where
+                for<'a> $inner: core::ops::$assign<$primitive>,
+            {
+                fn $assign_method(&mut self, rhs: $primitive) {
+                    core::ops::$assign::$assign_method(&mut self.0, rhs)
+                }
+            }
+        };
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_shift {
+        ($name:ident, $inner:ty, $trait:ident, $method:ident, $assign:ident, $assign_method:ident) => {
+            impl<Rhs> core::ops::$trait<Rhs> for $name
+            where
+                $inner: core::ops::$trait<Rhs, Output = $inner>,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: Rhs) -> Self::Output {
+                    Self(core::ops::$trait::$method(self.0, rhs))
+                }
+            }
+
+            impl<Rhs> core::ops::$assign<Rhs> for $name
+            where
+                $inner: core::ops::$assign<Rhs>,
+            {
+                fn $assign_method(&mut self, rhs: Rhs) {
+                    core::ops::$assign::$assign_method(&mut self.0, rhs)
+                }
+            }
+        };
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_unop {
+        ($name:ident, $inner:ty, $trait:ident, $method:ident) => {
+            impl core::ops::$trait for $name
+            where
+                for<'a> $inner: core::ops::$trait<Output = $inner>,
+            {
+                type Output = Self;
+
+                fn $method(self) -> Self::Output {
+                    Self(core::ops::$trait::$method(self.0))
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_num_traits {
+        ($name:ident, $inner:ty) => {
+            impl $crate::num_traits::Bounded for $name
+            where
+                for<'a> $inner: $crate::num_traits::Bounded,
+            {
+                fn min_value() -> Self {
+                    Self(<$inner as $crate::num_traits::Bounded>::min_value())
+                }
+
+                fn max_value() -> Self {
+                    Self(<$inner as $crate::num_traits::Bounded>::max_value())
+                }
+            }
+
+            $crate::newtype_checked!($name, $inner, CheckedAdd, checked_add);
+            $crate::newtype_checked!($name, $inner, CheckedDiv, checked_div);
+            $crate::newtype_checked!($name, $inner, CheckedMul, checked_mul);
+            $crate::newtype_checked!($name, $inner, CheckedRem, checked_rem);
+            $crate::newtype_checked!($name, $inner, CheckedSub, checked_sub);
+            $crate::newtype_saturating!($name, $inner, SaturatingAdd, saturating_add);
+            $crate::newtype_saturating!($name, $inner, SaturatingMul, saturating_mul);
+            $crate::newtype_saturating!($name, $inner, SaturatingSub, saturating_sub);
+
+            impl $crate::num_traits::CheckedNeg for $name
+            where
+                for<'a> $inner: $crate::num_traits::CheckedNeg,
+            {
+                fn checked_neg(&self) -> Option<Self> {
+                    $crate::num_traits::CheckedNeg::checked_neg(&self.0).map(Self)
+                }
+            }
+
+            impl $crate::num_traits::CheckedShl for $name
+            where
+                for<'a> $inner: $crate::num_traits::CheckedShl,
+            {
+                fn checked_shl(&self, rhs: u32) -> Option<Self> {
+                    $crate::num_traits::CheckedShl::checked_shl(&self.0, rhs).map(Self)
+                }
+            }
+
+            impl $crate::num_traits::CheckedShr for $name
+            where
+                for<'a> $inner: $crate::num_traits::CheckedShr,
+            {
+                fn checked_shr(&self, rhs: u32) -> Option<Self> {
+                    $crate::num_traits::CheckedShr::checked_shr(&self.0, rhs).map(Self)
+                }
+            }
+
+            impl $crate::num_traits::FromPrimitive for $name
+            where
+                for<'a> $inner: $crate::num_traits::FromPrimitive,
+            {
+                fn from_i64(n: i64) -> Option<Self> {
+                    <$inner as $crate::num_traits::FromPrimitive>::from_i64(n).map(Self)
+                }
+
+                fn from_i128(n: i128) -> Option<Self> {
+                    <$inner as $crate::num_traits::FromPrimitive>::from_i128(n).map(Self)
+                }
+
+                fn from_u64(n: u64) -> Option<Self> {
+                    <$inner as $crate::num_traits::FromPrimitive>::from_u64(n).map(Self)
+                }
+
+                fn from_u128(n: u128) -> Option<Self> {
+                    <$inner as $crate::num_traits::FromPrimitive>::from_u128(n).map(Self)
+                }
+            }
+
+            impl $crate::num_traits::Num for $name
+            where
+                for<'a> $inner: $crate::num_traits::Num,
+                for<'a> $name: PartialEq,
+            {
+                type FromStrRadixErr = <$inner as $crate::num_traits::Num>::FromStrRadixErr;
+
+                fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                    <$inner as $crate::num_traits::Num>::from_str_radix(str, radix).map(Self)
+                }
+            }
+
+            impl $crate::num_traits::NumCast for $name
+            where
+                for<'a> $inner: $crate::num_traits::NumCast,
+            {
+                fn from<N: $crate::num_traits::ToPrimitive>(n: N) -> Option<Self> {
+                    <$inner as $crate::num_traits::NumCast>::from(n).map(Self)
+                }
+            }
+
+            impl $crate::num_traits::One for $name
+            where
+                for<'a> $inner: $crate::num_traits::One,
+            {
+                fn one() -> Self {
+                    Self(<$inner as $crate::num_traits::One>::one())
+                }
+            }
+
+            impl $crate::num_traits::ToPrimitive for $name
+            where
+                for<'a> $inner: $crate::num_traits::ToPrimitive,
+            {
+                fn to_i64(&self) -> Option<i64> {
+                    $crate::num_traits::ToPrimitive::to_i64(&self.0)
+                }
+
+                fn to_i128(&self) -> Option<i128> {
+                    $crate::num_traits::ToPrimitive::to_i128(&self.0)
+                }
+
+                fn to_u64(&self) -> Option<u64> {
+                    $crate::num_traits::ToPrimitive::to_u64(&self.0)
+                }
+
+                fn to_u128(&self) -> Option<u128> {
+                    $crate::num_traits::ToPrimitive::to_u128(&self.0)
+                }
+            }
+
+            impl $crate::num_traits::Zero for $name
+            where
+                for<'a> $inner: $crate::num_traits::Zero,
+            {
+                fn zero() -> Self {
+                    Self(<$inner as $crate::num_traits::Zero>::zero())
+                }
+
+                fn is_zero(&self) -> bool {
+                    $crate::num_traits::Zero::is_zero(&self.0)
+                }
+            }
+        };
+    }
+
+    #[cfg(not(feature = "num-traits"))]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_num_traits {
+        ($name:ident, $inner:ty) => {};
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_checked {
+        ($name:ident, $inner:ty, $trait:ident, $method:ident) => {
+            impl $crate::num_traits::$trait for $name
+            where
+                for<'a> $inner: $crate::num_traits::$trait,
+            {
+                fn $method(&self, rhs: &Self) -> Option<Self> {
+                    $crate::num_traits::$trait::$method(&self.0, &rhs.0).map(Self)
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_saturating {
+        ($name:ident, $inner:ty, $trait:ident, $method:ident) => {
+            impl $crate::num_traits::$trait for $name
+            where
+                for<'a> $inner: $crate::num_traits::$trait,
+            {
+                fn $method(&self, rhs: &Self) -> Self {
+                    Self($crate::num_traits::$trait::$method(&self.0, &rhs.0))
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "saturating-div")]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_saturating_div {
+        ($name:ident, $inner:ty) => {
+            impl $crate::SaturatingDiv for $name
+            where
+                for<'a> $inner: $crate::SaturatingDiv,
+            {
+                fn saturating_div(&self, rhs: &Self) -> Self {
+                    Self($crate::SaturatingDiv::saturating_div(&self.0, &rhs.0))
+                }
+            }
+        };
+    }
+
+    #[cfg(not(feature = "saturating-div"))]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! newtype_saturating_div {
+        ($name:ident, $inner:ty) => {};
+    }
 }
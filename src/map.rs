@@ -0,0 +1,106 @@
+use core::ops::{Index, IndexMut};
+
+/// Dense map from every value of a bounded integer `K` to a `V`, stored as an
+/// array with one slot per key
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BoundedMap<K: Key, V>([V; K::COUNT])
+where
+    [(); K::COUNT]:;
+
+/// Bounded integer usable as the key of a `BoundedMap`
+pub trait Key: Copy + Sealed {
+    /// Count of values in bounds, fails to compile when it exceeds `usize`
+    const COUNT: usize;
+
+    /// Returns the offset from `MIN`
+    fn to_index(self) -> usize;
+
+    /// Returns `MIN` plus `index`, panicking unless it is less than `COUNT`
+    fn from_index(index: usize) -> Self;
+}
+
+pub trait Sealed {}
+
+impl<K: Key, V> BoundedMap<K, V>
+where
+    [(); K::COUNT]:,
+{
+    /// Creates a map by calling `f` with each key in ascending order
+    pub fn from_fn(mut f: impl FnMut(K) -> V) -> Self {
+        Self(core::array::from_fn(|index| f(K::from_index(index))))
+    }
+
+    /// Returns a map with `f` applied to each entry in ascending key order
+    pub fn map<W>(self, mut f: impl FnMut(K, V) -> W) -> BoundedMap<K, W> {
+        let mut index = 0;
+        BoundedMap(self.0.map(|value| {
+            let key = K::from_index(index);
+            index += 1;
+            f(key, value)
+        }))
+    }
+
+    /// Iterates over the entries in ascending key order
+    pub fn iter(&self) -> impl Iterator<Item = (K, &V)> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (K::from_index(index), value))
+    }
+
+    /// Iterates over the entries in ascending key order, with mutable values
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (K, &mut V)> {
+        self.0
+            .iter_mut()
+            .enumerate()
+            .map(|(index, value)| (K::from_index(index), value))
+    }
+
+    /// Returns the values in ascending key order
+    pub fn values(&self) -> &[V; K::COUNT] {
+        &self.0
+    }
+
+    /// Converts the map into its values in ascending key order
+    pub fn into_values(self) -> [V; K::COUNT] {
+        self.0
+    }
+}
+
+impl<K: Key, V: Default> Default for BoundedMap<K, V>
+where
+    [(); K::COUNT]:,
+{
+    fn default() -> Self {
+        Self::from_fn(|_| V::default())
+    }
+}
+
+impl<K: Key, V> From<[V; K::COUNT]> for BoundedMap<K, V>
+where
+    [(); K::COUNT]:,
+{
+    fn from(from: [V; K::COUNT]) -> Self {
+        Self(from)
+    }
+}
+
+impl<K: Key, V> Index<K> for BoundedMap<K, V>
+where
+    [(); K::COUNT]:,
+{
+    type Output = V;
+
+    fn index(&self, key: K) -> &V {
+        &self.0[key.to_index()]
+    }
+}
+
+impl<K: Key, V> IndexMut<K> for BoundedMap<K, V>
+where
+    [(); K::COUNT]:,
+{
+    fn index_mut(&mut self, key: K) -> &mut V {
+        &mut self.0[key.to_index()]
+    }
+}
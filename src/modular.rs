@@ -0,0 +1,82 @@
+//! Arithmetic modulo the count of values in a range, used to wrap results
+//! around the bounds.
+//!
+//! A modulus of 0 stands for 2^128, the count of values in a full `i128` or
+//! `u128` range.
+
+pub(crate) const fn modulus(min: i128, max: i128) -> u128 {
+    (max as u128).wrapping_sub(min as u128).wrapping_add(1)
+}
+
+pub(crate) const fn from_signed(value: i128, m: u128) -> u128 {
+    if m == 0 {
+        value as u128
+    } else if value < 0 {
+        (m - value.unsigned_abs() % m) % m
+    } else {
+        value as u128 % m
+    }
+}
+
+pub(crate) const fn from_unsigned(value: u128, m: u128) -> u128 {
+    if m == 0 {
+        value
+    } else {
+        value % m
+    }
+}
+
+/// Maps a residue onto the single value of `min..=max` congruent to it
+pub(crate) const fn wrap(residue: u128, min: i128, m: u128) -> u128 {
+    (min as u128).wrapping_add(sub(residue, from_signed(min, m), m))
+}
+
+pub(crate) const fn add(a: u128, b: u128, m: u128) -> u128 {
+    if m == 0 {
+        a.wrapping_add(b)
+    } else if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+pub(crate) const fn sub(a: u128, b: u128, m: u128) -> u128 {
+    if m == 0 {
+        a.wrapping_sub(b)
+    } else if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+pub(crate) const fn mul(mut a: u128, mut b: u128, m: u128) -> u128 {
+    if m == 0 {
+        return a.wrapping_mul(b);
+    }
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            product = add(product, a, m);
+        }
+        a = add(a, a, m);
+        b >>= 1;
+    }
+    product
+}
+
+pub(crate) const fn pow(mut base: u128, mut exp: u32, m: u128) -> u128 {
+    if m == 0 {
+        return base.wrapping_pow(exp);
+    }
+    let mut power = 1 % m;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            power = mul(power, base, m);
+        }
+        base = mul(base, base, m);
+        exp >>= 1;
+    }
+    power
+}
@@ -0,0 +1,173 @@
+use crate::Key;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::FromIterator;
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+};
+
+/// Set of values of a bounded integer `K`, stored as a bitmap with one bit
+/// per key
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct BoundedSet<K: Key>([u64; K::COUNT.div_ceil(64)])
+where
+    [(); K::COUNT.div_ceil(64)]:;
+
+impl<K: Key> BoundedSet<K>
+where
+    [(); K::COUNT.div_ceil(64)]:,
+{
+    /// Returns the empty set
+    pub fn new() -> Self {
+        Self([0; K::COUNT.div_ceil(64)])
+    }
+
+    /// Returns the set of all the keys
+    pub fn full() -> Self {
+        !Self::new()
+    }
+
+    /// Returns the number of keys
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns whether the set has no key
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    /// Returns whether the key is in the set
+    pub fn contains(&self, key: K) -> bool {
+        let (word, bit) = Self::position(key);
+        self.0[word] & bit != 0
+    }
+
+    /// Adds a key, returning whether it was absent
+    pub fn insert(&mut self, key: K) -> bool {
+        let (word, bit) = Self::position(key);
+        let absent = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+        absent
+    }
+
+    /// Removes a key, returning whether it was present
+    pub fn remove(&mut self, key: K) -> bool {
+        let (word, bit) = Self::position(key);
+        let present = self.0[word] & bit != 0;
+        self.0[word] &= !bit;
+        present
+    }
+
+    /// Iterates over the keys in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = K> + '_ {
+        self.0.iter().enumerate().flat_map(|(index, &word)| {
+            let mut word = word;
+            core::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(K::from_index(index * 64 + bit))
+            })
+        })
+    }
+
+    fn position(key: K) -> (usize, u64) {
+        let index = key.to_index();
+        (index / 64, 1 << (index % 64))
+    }
+}
+
+impl<K: Key + Debug> Debug for BoundedSet<K>
+where
+    [(); K::COUNT.div_ceil(64)]:,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Key> Default for BoundedSet<K>
+where
+    [(); K::COUNT.div_ceil(64)]:,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Key> Extend<K> for BoundedSet<K>
+where
+    [(); K::COUNT.div_ceil(64)]:,
+{
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
+
+impl<K: Key> FromIterator<K> for BoundedSet<K>
+where
+    [(); K::COUNT.div_ceil(64)]:,
+{
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// Complement, the bits past the last key stay unset
+impl<K: Key> Not for BoundedSet<K>
+where
+    [(); K::COUNT.div_ceil(64)]:,
+{
+    type Output = Self;
+
+    fn not(mut self) -> Self::Output {
+        for word in &mut self.0 {
+            *word = !*word;
+        }
+        if K::COUNT % 64 != 0 {
+            self.0[K::COUNT / 64] &= (1 << (K::COUNT % 64)) - 1;
+        }
+        self
+    }
+}
+
+macro_rules! set_operator {
+    ($trait:ident, $method:ident, $assign:ident, $assign_method:ident, |$lhs:ident, $rhs:ident| $word:expr) => {
+        impl<K: Key> $trait for BoundedSet<K>
+        where
+            [(); K::COUNT.div_ceil(64)]:,
+        {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self::Output {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl<K: Key> $assign for BoundedSet<K>
+        where
+            [(); K::COUNT.div_ceil(64)]:,
+        {
+            fn $assign_method(&mut self, rhs: Self) {
+                for ($lhs, $rhs) in self.0.iter_mut().zip(rhs.0) {
+                    *$lhs = $word;
+                }
+            }
+        }
+    };
+}
+
+set_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, |lhs, rhs| *lhs
+    & rhs);
+set_operator!(BitOr, bitor, BitOrAssign, bitor_assign, |lhs, rhs| *lhs
+    | rhs);
+set_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign, |lhs, rhs| *lhs
+    ^ rhs);
+set_operator!(Sub, sub, SubAssign, sub_assign, |lhs, rhs| *lhs & !rhs);
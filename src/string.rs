@@ -0,0 +1,159 @@
+use crate::{Bounded, BoundedUsize, LengthError};
+use core::convert::TryFrom;
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::str::FromStr;
+
+/// String whose length, counted in `U`, is bounded by closed interval
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BoundedString<const MIN: usize, const MAX: usize, U: LengthUnit = Bytes>(
+    String,
+    PhantomData<U>,
+);
+
+/// Unit in which the length of a `BoundedString` is counted
+pub trait LengthUnit: Sealed {
+    /// Returns the length of `s` counted in this unit
+    fn len(s: &str) -> usize;
+}
+
+pub trait Sealed {}
+
+/// Length counted in UTF-8 bytes
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Bytes;
+
+/// Length counted in `char`s
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Chars;
+
+impl Sealed for Bytes {}
+
+impl LengthUnit for Bytes {
+    fn len(s: &str) -> usize {
+        s.len()
+    }
+}
+
+impl Sealed for Chars {}
+
+impl LengthUnit for Chars {
+    fn len(s: &str) -> usize {
+        s.chars().count()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, U: LengthUnit> BoundedString<MIN, MAX, U> {
+    /// Fails to compile when `MIN` is greater than `MAX`
+    const VALID: () = assert!(MIN <= MAX, "MIN is greater than MAX");
+
+    /// Returns the length counted in `U`
+    pub fn len(&self) -> BoundedUsize<MIN, MAX> {
+        Bounded(U::len(&self.0))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Appends a char, failing when the string would be too long
+    pub fn push(&mut self, c: char) -> Result<(), LengthError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Appends a string slice, failing when the string would be too long
+    pub fn push_str(&mut self, s: &str) -> Result<(), LengthError> {
+        if U::len(&self.0) + U::len(s) > MAX {
+            return Err(LengthError::TooLong);
+        }
+        self.0.push_str(s);
+        Ok(())
+    }
+
+    /// Removes the last char, failing when the string would be too short
+    pub fn pop(&mut self) -> Result<char, LengthError> {
+        let c = self.0.chars().next_back().ok_or(LengthError::TooShort)?;
+        if U::len(&self.0) - U::len(c.encode_utf8(&mut [0; 4])) < MIN {
+            return Err(LengthError::TooShort);
+        }
+        self.0.pop();
+        Ok(c)
+    }
+
+    /// Shortens the string to `new_len` bytes, failing when it would be too
+    /// short
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a char boundary.
+    pub fn truncate(&mut self, new_len: usize) -> Result<(), LengthError> {
+        if new_len < self.0.len() && U::len(&self.0[..new_len]) < MIN {
+            return Err(LengthError::TooShort);
+        }
+        self.0.truncate(new_len);
+        Ok(())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, U: LengthUnit> AsRef<str> for BoundedString<MIN, MAX, U> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, U: LengthUnit> Deref for BoundedString<MIN, MAX, U> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, U: LengthUnit> Display for BoundedString<MIN, MAX, U> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, U: LengthUnit> From<BoundedString<MIN, MAX, U>>
+    for String
+{
+    fn from(from: BoundedString<MIN, MAX, U>) -> Self {
+        from.0
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, U: LengthUnit> FromStr for BoundedString<MIN, MAX, U> {
+    type Err = LengthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.to_owned())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, U: LengthUnit> TryFrom<String>
+    for BoundedString<MIN, MAX, U>
+{
+    type Error = LengthError;
+
+    fn try_from(from: String) -> Result<Self, Self::Error> {
+        let () = Self::VALID;
+        let len = U::len(&from);
+        if len < MIN {
+            Err(LengthError::TooShort)
+        } else if len > MAX {
+            Err(LengthError::TooLong)
+        } else {
+            Ok(Self(from, PhantomData))
+        }
+    }
+}
@@ -22,6 +22,96 @@ mod new {
     }
 }
 
+mod atomic {
+    use super::*;
+    use crate::AtomicBounded;
+    use std::sync::atomic::Ordering::SeqCst;
+    use std::{sync::Arc, thread};
+
+    type Slot = Bounded<u32, 0, 8>;
+
+    type Slots = AtomicBounded<u32, 0, 8>;
+
+    #[test]
+    fn compare_exchange() {
+        let slots = Slots::new(Slot::new(3));
+        assert_eq!(
+            Ok(Slot::new(3)),
+            slots.compare_exchange(Slot::new(3), Slot::new(4), SeqCst, SeqCst)
+        );
+        assert_eq!(
+            Err(Slot::new(4)),
+            slots.compare_exchange(Slot::new(3), Slot::new(5), SeqCst, SeqCst)
+        );
+    }
+
+    #[test]
+    fn checked_fetch_add() {
+        let slots = Slots::new(Slot::new(7));
+        assert_eq!(Ok(Slot::new(7)), slots.checked_fetch_add(1, SeqCst));
+        assert_eq!(Err(Slot::new(8)), slots.checked_fetch_add(1, SeqCst));
+        assert_eq!(Slot::new(8), slots.load(SeqCst));
+    }
+
+    #[test]
+    fn checked_fetch_sub() {
+        let slots = Slots::new(Slot::new(1));
+        assert_eq!(Ok(Slot::new(1)), slots.checked_fetch_sub(1, SeqCst));
+        assert_eq!(Err(Slot::new(0)), slots.checked_fetch_sub(1, SeqCst));
+    }
+
+    #[test]
+    fn saturating_fetch_add() {
+        let slots = Arc::new(Slots::new(Slot::new(0)));
+        let threads: Vec<_> = (0..16)
+            .map(|_| {
+                let slots = slots.clone();
+                thread::spawn(move || slots.saturating_fetch_add(1, SeqCst))
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(Slot::new(8), slots.load(SeqCst));
+    }
+
+    #[test]
+    fn saturating_fetch_sub() {
+        type Offset = Bounded<i8, -3, 3>;
+
+        let offset = AtomicBounded::<i8, -3, 3>::new(Offset::new(-2));
+        assert_eq!(Offset::new(-2), offset.saturating_fetch_sub(5, SeqCst));
+        assert_eq!(Offset::new(-3), offset.saturating_fetch_sub(-7, SeqCst));
+        assert_eq!(Offset::new(3), offset.load(SeqCst));
+    }
+}
+
+mod checked_set {
+    use super::*;
+
+    #[test]
+    fn in_bounds() {
+        let mut digit = Digit::new(0);
+        assert_eq!(Some(()), digit.checked_set(9));
+        assert_eq!(Digit::new(9), digit);
+        digit.set(3);
+        assert_eq!(Digit::new(3), digit);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let mut digit = Digit::new(3);
+        assert_eq!(None, digit.checked_set(10));
+        assert_eq!(Digit::new(3), digit);
+    }
+
+    #[test]
+    #[should_panic(expected = "value is out of bounds")]
+    fn set_out_of_bounds() {
+        Digit::new(3).set(-1);
+    }
+}
+
 mod checked_new {
     use super::*;
 
@@ -36,6 +126,460 @@ mod checked_new {
     }
 }
 
+mod default {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(Digit::new(0), Digit::default());
+        assert_eq!(Z::new(0), Z::default());
+        assert_eq!(
+            Bounded::<u128, 0, { u128::MAX as i128 }>::new(0),
+            Default::default()
+        );
+    }
+}
+
+mod single_value {
+    use super::*;
+
+    type Seven = Bounded<i8, 7, 7>;
+
+    type Zero = Bounded<u128, 0, 0>;
+
+    #[test]
+    fn new() {
+        assert_eq!(7, Seven::new(7).get());
+        assert_eq!(None, Seven::checked_new(6));
+        assert_eq!(None, Seven::checked_new(8));
+        assert_eq!(Some(Zero::new(0)), Zero::checked_new(0));
+        assert_eq!(None, Zero::checked_new(1));
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(
+            Seven::new(7),
+            Seven::new(7).const_wrapping_add(Seven::new(7))
+        );
+        assert_eq!(
+            Seven::new(7),
+            Seven::new(7).const_wrapping_mul(Seven::new(7))
+        );
+    }
+
+    #[test]
+    fn saturating() {
+        assert_eq!(
+            Seven::new(7),
+            Seven::new(7).const_saturating_add(Seven::new(7))
+        );
+    }
+}
+
+mod alias {
+    use super::*;
+    use crate::{BoundedI8, BoundedU128, BoundedU8};
+
+    #[test]
+    fn same_type() {
+        let digit: BoundedU8<0, 9> = Bounded::<u8, 0, 9>::new(7);
+        let generic: Bounded<u8, 0, 9> = digit + BoundedU8::<0, 9>::new(1);
+        assert_eq!(Bounded::<u8, 0, 9>::new(8), generic);
+        assert_eq!("Bounded(8 in 0..=9)", format!("{:#?}", generic));
+    }
+
+    #[test]
+    fn full_range() {
+        type Wide = BoundedU128<0, { u128::MAX }>;
+        type Byte = BoundedI8<{ i8::MIN }, { i8::MAX }>;
+
+        assert_eq!(u128::MAX, Wide::new(u128::MAX).get());
+        let byte: Z = Byte::new(i8::MAX);
+        assert_eq!(Byte::new(i8::MIN), byte.const_wrapping_add(Byte::new(1)));
+    }
+}
+
+mod negative {
+    use super::*;
+    use core::ops::{Bound, RangeBounds};
+
+    type Celsius = Bounded<i32, -100, -1>;
+
+    #[test]
+    fn new() {
+        assert_eq!(-1, Celsius::new(-1).get());
+        assert_eq!(None, Celsius::checked_new(0));
+        assert_eq!(None, Celsius::checked_new(-101));
+    }
+
+    #[test]
+    fn range_bounds() {
+        let celsius = Celsius::new(-50);
+        assert_eq!(Bound::Included(&-100), celsius.start_bound());
+        assert_eq!(Bound::Included(&-1), celsius.end_bound());
+    }
+
+    #[test]
+    fn saturating() {
+        assert_eq!(
+            Celsius::new(-1),
+            Celsius::new(-2).const_saturating_sub(Celsius::new(-50))
+        );
+        assert_eq!(
+            Celsius::new(-100),
+            Celsius::new(-60).const_saturating_add(Celsius::new(-50))
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(
+            Celsius::new(-99),
+            Celsius::new(-1).const_wrapping_sub(Celsius::new(-2))
+        );
+        assert_eq!(
+            Celsius::new(-1),
+            Celsius::new(-100).const_wrapping_add(Celsius::new(-1))
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "Bounded(-5 in -100..=-1)",
+            format!("{:#?}", Celsius::new(-5))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "value 0 is out of bounds -100..=-1")]
+    fn literal() {
+        let _ = Celsius::from_literal(0);
+    }
+}
+
+mod bounded {
+    use super::*;
+
+    #[test]
+    fn literal() {
+        assert_eq!(Digit::new(7), crate::bounded!(Digit, 7));
+        assert_eq!(Z::new(i8::MIN), crate::bounded!(Z, i8::MIN));
+    }
+
+    #[test]
+    #[should_panic(expected = "value -1 is out of bounds 0..=9")]
+    fn out_of_min_bound() {
+        let _ = Digit::from_literal(-1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "value 340282366920938463463374607431768211455 is out of bounds 1..=9"
+    )]
+    fn out_of_max_bound() {
+        let _ = Bounded::<u128, 1, 9>::from_literal(u128::MAX);
+    }
+}
+
+mod abs {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the absolute value with out of bounds")]
+    fn out_of_bounds() {
+        let _ = Bounded::<i8, -10, 5>::new(-6).abs();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn overflow() {
+        let _ = Z::new(i8::MIN).abs();
+    }
+}
+
+mod abs_diff {
+    use super::*;
+
+    #[test]
+    fn abs_diff() {
+        type Offset = Bounded<i8, -3, 5>;
+
+        assert_eq!(
+            Bounded::<u8, 0, 8>::new(8),
+            Offset::new(-3).abs_diff(Offset::new(5))
+        );
+    }
+
+    #[test]
+    fn full_range() {
+        assert_eq!(
+            Bounded::<u8, 0, 255>::new(u8::MAX),
+            Z::new(i8::MIN).abs_diff(Z::new(i8::MAX))
+        );
+    }
+}
+
+mod div_euclid {
+    use super::*;
+
+    #[test]
+    fn div_euclid() {
+        type Offset = Bounded<i8, -10, 10>;
+
+        assert_eq!(Offset::new(-4), Offset::new(-7).div_euclid(Digit::new(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with out of bounds")]
+    fn out_of_bounds() {
+        let _ = N1::new(1).div_euclid(Digit::new(2));
+    }
+}
+
+mod rem_euclid {
+    use super::*;
+
+    #[test]
+    fn rem_euclid() {
+        assert_eq!(
+            Bounded::<i8, 0, 8>::new(1),
+            Z::new(-7).rem_euclid(Bounded::<i8, 1, 9>::new(4))
+        );
+    }
+
+    #[test]
+    fn unsigned_divisor_above_i128_max() {
+        type Divisor = Bounded<u128, { u128::MAX as i128 - 1 }, { u128::MAX as i128 }>;
+
+        assert_eq!(
+            Bounded::<u128, 0, { u128::MAX as i128 - 1 }>::new(7),
+            Bounded::<u128, 0, 9>::new(7).rem_euclid(Divisor::new(u128::MAX))
+        );
+    }
+}
+
+mod signum {
+    use super::*;
+
+    #[test]
+    fn signum() {
+        type Sign = Bounded<i8, -1, 1>;
+
+        assert_eq!(Sign::new(-1), Z::new(-7).signum());
+        assert_eq!(Sign::new(0), Z::new(0).signum());
+        assert_eq!(Sign::new(1), Z::new(7).signum());
+    }
+}
+
+mod const_fn {
+    use super::*;
+
+    type Percent = Bounded<u8, 0, 100>;
+
+    const STEP: Percent = Percent::new(25);
+
+    const LIMITS: [Percent; 4] = [
+        STEP,
+        STEP.const_add(STEP),
+        STEP.const_mul(Percent::new(3)),
+        match STEP.const_checked_mul(Percent::new(4)) {
+            Some(limit) => limit,
+            None => Percent::new(100),
+        },
+    ];
+
+    #[test]
+    fn table() {
+        assert_eq!([25, 50, 75, 100], LIMITS.map(Percent::get));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with out of bounds")]
+    fn const_add() {
+        let _ = Digit::new(9).const_add(Digit::new(1));
+    }
+
+    #[test]
+    fn const_checked_div() {
+        assert_eq!(None, N1::new(1).const_checked_div(N1::new(9)));
+        assert_eq!(None, Z::new(1).const_checked_div(Z::new(0)));
+    }
+
+    #[test]
+    fn const_saturating_add() {
+        type Offset = Bounded<i8, -10, 10>;
+
+        assert_eq!(
+            Offset::new(10),
+            Offset::new(5).const_saturating_add(Offset::new(6))
+        );
+        assert_eq!(
+            Offset::new(-10),
+            Offset::new(-5).const_saturating_add(Offset::new(-6))
+        );
+    }
+
+    #[test]
+    fn const_wrapping_add() {
+        assert_eq!(
+            Digit::new(1),
+            Digit::new(9).const_wrapping_add(Digit::new(2))
+        );
+        assert_eq!(N1::new(127), N1::new(1).const_wrapping_sub(N1::new(1)));
+        assert_eq!(
+            Z::new(i8::MIN),
+            Z::new(i8::MAX).const_wrapping_add(Z::new(1))
+        );
+    }
+
+    #[test]
+    fn const_wrapping_mul() {
+        assert_eq!(
+            Digit::new(1),
+            Digit::new(9).const_wrapping_mul(Digit::new(9))
+        );
+    }
+}
+
+mod fmt {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!("Bounded(5)", format!("{:?}", Digit::new(5)));
+        assert_eq!("Bounded(5 in 0..=9)", format!("{:#?}", Digit::new(5)));
+        assert_eq!(
+            format!("Bounded(5 in 0..={})", u128::MAX),
+            format!("{:#?}", Bounded::<u128, 0, { u128::MAX as i128 }>::new(5))
+        );
+    }
+
+    #[test]
+    fn exp() {
+        assert_eq!("9e0", format!("{:e}", Digit::new(9)));
+        assert_eq!("9E0", format!("{:E}", Digit::new(9)));
+    }
+
+    #[test]
+    fn radix() {
+        type Byte = Bounded<u8, 0, 255>;
+
+        assert_eq!("0x0f", format!("{:#04x}", Byte::new(15)));
+        assert_eq!("0x0F", format!("{:#04X}", Byte::new(15)));
+        assert_eq!("17", format!("{:o}", Byte::new(15)));
+        assert_eq!("00001111", format!("{:08b}", Byte::new(15)));
+    }
+}
+
+mod ilog {
+    use super::*;
+
+    #[test]
+    fn ilog2() {
+        assert_eq!(Digit::new(3), Digit::new(9).ilog2());
+    }
+
+    #[test]
+    fn ilog10() {
+        assert_eq!(Z::new(2), Z::new(127).ilog10());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the logarithm with out of bounds")]
+    fn out_of_bounds() {
+        let _ = N1::new(1).ilog2();
+    }
+}
+
+mod isqrt {
+    use super::*;
+
+    #[test]
+    fn isqrt() {
+        assert_eq!(Digit::new(2), Digit::new(8).isqrt());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the square root with out of bounds")]
+    fn out_of_bounds() {
+        let _ = Bounded::<u8, 4, 9>::new(9).isqrt();
+    }
+}
+
+mod pow {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the power with out of bounds")]
+    fn out_of_bounds() {
+        let _ = Digit::new(2).pow(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn overflow() {
+        let _ = Z::new(2).pow(7);
+    }
+}
+
+mod checked_pow {
+    use super::*;
+
+    #[test]
+    fn out_of_bounds() {
+        assert_eq!(None, Digit::new(2).checked_pow(4));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(None, Z::new(2).checked_pow(7));
+    }
+}
+
+mod saturating_pow {
+    use super::*;
+
+    #[test]
+    fn out_of_max_bound() {
+        assert_eq!(Digit::new(9), Digit::new(2).saturating_pow(4));
+    }
+
+    #[test]
+    fn out_of_min_bound() {
+        type Offset = Bounded<i8, -10, 10>;
+
+        assert_eq!(Offset::new(-10), Offset::new(-3).saturating_pow(3));
+    }
+}
+
+mod wrapping_pow {
+    use super::*;
+
+    #[test]
+    fn out_of_bounds() {
+        assert_eq!(Digit::new(7), Digit::new(3).wrapping_pow(3));
+        assert_eq!(N1::new(1), N1::new(2).wrapping_pow(7));
+    }
+
+    #[test]
+    fn negative() {
+        type Offset = Bounded<i8, -10, 10>;
+
+        assert_eq!(Offset::new(-6), Offset::new(-3).wrapping_pow(3));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(Z::new(2i8.wrapping_pow(7)), Z::new(2).wrapping_pow(7));
+        assert_eq!(
+            Bounded::<u128, 0, { u128::MAX as i128 }>::new(3u128.wrapping_pow(99)),
+            Bounded::<u128, 0, { u128::MAX as i128 }>::new(3).wrapping_pow(99)
+        );
+    }
+}
+
 mod traits {
     use super::*;
 
@@ -53,6 +597,38 @@ mod traits {
         fn overflow() {
             let _ = Z::new(i8::MAX) + Z::new(1);
         }
+
+        #[test]
+        fn reference() {
+            let (lhs, rhs) = (&Digit::new(4), &Digit::new(5));
+            assert_eq!(Digit::new(9), lhs + rhs);
+            assert_eq!(Digit::new(9), lhs + 5);
+            assert_eq!(Digit::new(9), Digit::new(4) + rhs);
+        }
+
+        #[test]
+        fn reversed() {
+            let (lhs, rhs) = (&4, &Digit::new(5));
+            assert_eq!(Digit::new(9), 4 + Digit::new(5));
+            assert_eq!(Digit::new(9), lhs + rhs);
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to add with out of bounds")]
+        fn reversed_out_of_bounds() {
+            let _ = 1 + Digit::new(9);
+        }
+
+        #[test]
+        fn other_bounds() {
+            assert_eq!(Digit::new(9), Digit::new(4) + Bounded::<i8, 0, 99>::new(5));
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to add with out of bounds")]
+        fn other_bounds_out_of_bounds() {
+            let _ = Digit::new(9) + Bounded::<i8, 0, 99>::new(10);
+        }
     }
 
     mod add_assign {
@@ -71,6 +647,84 @@ mod traits {
             let mut byte = Z::new(i8::MAX);
             byte += Z::new(1);
         }
+
+        #[test]
+        fn reference() {
+            let mut digit = Digit::new(4);
+            digit += &Digit::new(5);
+            assert_eq!(Digit::new(9), digit);
+        }
+    }
+
+    mod bit_and {
+        use super::*;
+
+        #[test]
+        fn in_bounds() {
+            assert_eq!(
+                Bounded::<u8, 0, 9>::new(1),
+                Bounded::<u8, 0, 9>::new(9) & Bounded::<u8, 0, 9>::new(3)
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to perform bitwise and with out of bounds")]
+        fn out_of_bounds() {
+            let _ = N1::new(1) & N1::new(2);
+        }
+    }
+
+    mod bit_and_assign {
+        use super::*;
+
+        #[test]
+        #[should_panic(expected = "attempt to perform bitwise and with out of bounds")]
+        fn out_of_bounds() {
+            let mut digit = N1::new(1);
+            digit &= N1::new(2);
+        }
+    }
+
+    mod bit_or {
+        use super::*;
+
+        #[test]
+        #[should_panic(expected = "attempt to perform bitwise or with out of bounds")]
+        fn out_of_bounds() {
+            let _ = Digit::new(8) | Digit::new(7);
+        }
+    }
+
+    mod bit_or_assign {
+        use super::*;
+
+        #[test]
+        #[should_panic(expected = "attempt to perform bitwise or with out of bounds")]
+        fn out_of_bounds() {
+            let mut digit = Digit::new(8);
+            digit |= Digit::new(7);
+        }
+    }
+
+    mod bit_xor {
+        use super::*;
+
+        #[test]
+        #[should_panic(expected = "attempt to perform bitwise xor with out of bounds")]
+        fn out_of_bounds() {
+            let _ = N1::new(1) ^ N1::new(1);
+        }
+    }
+
+    mod bit_xor_assign {
+        use super::*;
+
+        #[test]
+        #[should_panic(expected = "attempt to perform bitwise xor with out of bounds")]
+        fn out_of_bounds() {
+            let mut digit = N1::new(1);
+            digit ^= N1::new(1);
+        }
     }
 
     mod div {
@@ -184,21 +838,32 @@ mod traits {
         fn overflow() {
             let _ = -Z::new(i8::MIN);
         }
+
+        #[test]
+        fn reference() {
+            assert_eq!(Z::new(-1), -&Z::new(1));
+        }
     }
 
-    mod range_bounds {
+    mod not {
         use super::*;
 
         #[test]
-        #[should_panic(expected = "assertion failed: MIN >= <i8>::MIN as i128")]
-        fn min_less_type_min() {
-            let _ = Bounded::<i8, { i8::MIN as i128 - 1 }, { i8::MAX as u128 }>::new(0);
+        #[should_panic(expected = "attempt to perform bitwise not with out of bounds")]
+        fn out_of_bounds() {
+            let _ = !Digit::new(0);
         }
+    }
+
+    mod range_bounds {
+        use super::*;
+        use core::ops::{Bound, RangeBounds};
 
         #[test]
-        #[should_panic(expected = "assertion failed: MAX <= <i8>::MAX as u128")]
-        fn max_greater_type_max() {
-            let _ = Bounded::<i8, { i8::MIN as i128 }, { i8::MAX as u128 + 1 }>::new(0);
+        fn single_value() {
+            let seven = Bounded::<u8, 7, 7>::new(7);
+            assert_eq!(Bound::Included(&7), seven.start_bound());
+            assert_eq!(Bound::Included(&7), seven.end_bound());
         }
     }
 
@@ -263,6 +928,13 @@ mod traits {
         fn overflow() {
             let _ = Z::new(1) << 8;
         }
+
+        #[test]
+        fn reversed() {
+            let (lhs, rhs) = (&1u8, &Digit::new(3));
+            assert_eq!(8u8, 1u8 << Digit::new(3));
+            assert_eq!(8u8, lhs << rhs);
+        }
     }
 
     mod shl_assign {
@@ -331,6 +1003,12 @@ mod traits {
         fn overflow() {
             let _ = Z::new(i8::MIN) - Z::new(1);
         }
+
+        #[test]
+        #[should_panic(expected = "attempt to subtract with out of bounds")]
+        fn reversed_out_of_bounds() {
+            let _ = 0 - Digit::new(1);
+        }
     }
 
     mod sub_assign {
@@ -349,6 +1027,13 @@ mod traits {
             let mut byte = Z::new(i8::MIN);
             byte -= Z::new(1);
         }
+
+        #[test]
+        fn other_bounds() {
+            let mut digit = Digit::new(9);
+            digit -= N1::new(9);
+            assert_eq!(Digit::new(0), digit);
+        }
     }
 }
 
@@ -361,10 +1046,16 @@ mod traits {
     feature = "checked-shl",
     feature = "checked-shr",
     feature = "checked-sub",
+    feature = "from-primitive",
+    feature = "num",
+    feature = "num-cast",
+    feature = "one",
     feature = "saturating-add",
     feature = "saturating-div",
     feature = "saturating-mul",
     feature = "saturating-sub",
+    feature = "to-primitive",
+    feature = "zero",
 ))]
 mod extra_traits {
     use super::*;
@@ -489,16 +1180,89 @@ mod extra_traits {
     #[cfg(feature = "checked-sub")]
     mod checked_sub {
         use super::*;
-        use num_traits::CheckedSub;
+        use num_traits::CheckedSub;
+
+        #[test]
+        fn out_of_bounds() {
+            assert_eq!(None, N1::new(1).checked_sub(&N1::new(1)));
+        }
+
+        #[test]
+        fn overflow() {
+            assert_eq!(None, Z::new(i8::MIN).checked_sub(&Z::new(1)));
+        }
+    }
+
+    #[cfg(feature = "from-primitive")]
+    mod from_primitive {
+        use super::*;
+        use num_traits::FromPrimitive;
+
+        #[test]
+        fn in_bounds() {
+            assert_eq!(Some(Digit::new(9)), Digit::from_u64(9));
+        }
+
+        #[test]
+        fn out_of_bounds() {
+            assert_eq!(None, Digit::from_i64(-1));
+        }
+
+        #[test]
+        fn overflow() {
+            assert_eq!(None, Z::from_u128(u128::MAX));
+        }
+    }
+
+    #[cfg(feature = "num")]
+    mod num {
+        use super::*;
+        use crate::ParseError;
+        use num_traits::Num;
+
+        #[test]
+        fn from_str_radix() {
+            assert_eq!(Ok(Digit::new(7)), Digit::from_str_radix("111", 2));
+        }
+
+        #[test]
+        fn invalid_digit() {
+            assert!(matches!(
+                Digit::from_str_radix("9", 8),
+                Err(ParseError::Int(_))
+            ));
+        }
+
+        #[test]
+        fn out_of_bounds() {
+            assert_eq!(Err(ParseError::OutOfBounds), Digit::from_str_radix("a", 16));
+        }
+    }
+
+    #[cfg(feature = "num-cast")]
+    mod num_cast {
+        use super::*;
+        use num_traits::NumCast;
+
+        #[test]
+        fn in_bounds() {
+            assert_eq!(Some(Digit::new(9)), <Digit as NumCast>::from(9.5));
+        }
 
         #[test]
         fn out_of_bounds() {
-            assert_eq!(None, N1::new(1).checked_sub(&N1::new(1)));
+            assert_eq!(None, <Digit as NumCast>::from(10u64));
         }
+    }
+
+    #[cfg(feature = "one")]
+    mod one {
+        use super::*;
+        use num_traits::One;
 
         #[test]
-        fn overflow() {
-            assert_eq!(None, Z::new(i8::MIN).checked_sub(&Z::new(1)));
+        fn one() {
+            assert_eq!(N1::new(1), N1::one());
         }
     }
 
@@ -516,6 +1280,21 @@ mod extra_traits {
         fn overflow() {
             assert_eq!(Z::new(i8::MAX), Z::new(i8::MAX).saturating_add(&Z::new(1)));
         }
+
+        #[test]
+        fn negative() {
+            type Celsius = Bounded<i32, -100, -1>;
+            type Offset = Bounded<i8, -10, 10>;
+
+            assert_eq!(
+                Celsius::new(-100),
+                Celsius::new(-60).saturating_add(&Celsius::new(-50))
+            );
+            assert_eq!(
+                Offset::new(-10),
+                Offset::new(-8).saturating_add(&Offset::new(-8))
+            );
+        }
     }
 
     #[cfg(feature = "saturating-div")]
@@ -533,6 +1312,16 @@ mod extra_traits {
         fn out_of_bounds() {
             assert_eq!(N1::new(1), N1::new(1).saturating_div(&N1::new(9)));
         }
+
+        #[test]
+        fn negative() {
+            type Celsius = Bounded<i8, -10, -1>;
+
+            assert_eq!(
+                Celsius::new(-1),
+                Celsius::new(-10).saturating_div(&Celsius::new(-1))
+            );
+        }
     }
 
     #[cfg(feature = "saturating-mul")]
@@ -549,6 +1338,16 @@ mod extra_traits {
         fn overflow() {
             assert_eq!(Z::new(i8::MAX), Z::new(i8::MAX).saturating_mul(&Z::new(2)));
         }
+
+        #[test]
+        fn negative() {
+            type Offset = Bounded<i8, -10, 10>;
+
+            assert_eq!(
+                Offset::new(-10),
+                Offset::new(5).saturating_mul(&Offset::new(-5))
+            );
+        }
     }
 
     #[cfg(feature = "saturating-sub")]
@@ -565,5 +1364,669 @@ mod extra_traits {
         fn overflow() {
             assert_eq!(Z::new(i8::MIN), Z::new(i8::MIN).saturating_sub(&Z::new(1)));
         }
+
+        #[test]
+        fn negative() {
+            type Celsius = Bounded<i32, -100, -1>;
+
+            assert_eq!(
+                Celsius::new(-1),
+                Celsius::new(-2).saturating_sub(&Celsius::new(-50))
+            );
+        }
+    }
+
+    #[cfg(feature = "to-primitive")]
+    mod to_primitive {
+        use super::*;
+        use num_traits::ToPrimitive;
+
+        #[test]
+        fn in_bounds() {
+            assert_eq!(Some(9), Digit::new(9).to_u8());
+        }
+
+        #[test]
+        fn out_of_bounds() {
+            assert_eq!(None, Z::new(-1).to_u8());
+        }
+    }
+
+    #[cfg(feature = "zero")]
+    mod zero {
+        use super::*;
+        use num_traits::Zero;
+
+        #[test]
+        fn zero() {
+            assert_eq!(Digit::new(0), Digit::zero());
+        }
+
+        #[test]
+        fn is_zero() {
+            assert!(Digit::new(0).is_zero());
+            assert!(!Digit::new(9).is_zero());
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand {
+    use super::*;
+    use ::rand::{rngs::StdRng, Rng, SeedableRng};
+
+    type Die = Bounded<u8, 1, 6>;
+
+    #[test]
+    fn standard() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut counts = [0; 6];
+        for _ in 0..600 {
+            let die: Die = rng.gen();
+            counts[*die as usize - 1] += 1;
+        }
+        assert!(counts.iter().all(|&count| count > 0));
+    }
+
+    #[test]
+    fn gen_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..600 {
+            let die = rng.gen_range(Die::new(2)..=Die::new(5));
+            assert!((2..=5).contains(&*die));
+            let die = rng.gen_range(Die::new(2)..Die::new(5));
+            assert!((2..5).contains(&*die));
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest {
+    use super::*;
+    use ::proptest::prelude::*;
+    use ::proptest::strategy::ValueTree;
+    use ::proptest::test_runner::TestRunner;
+
+    proptest! {
+        #[test]
+        fn arbitrary(digit: Digit, n1: N1) {
+            prop_assert!((0..=9).contains(&*digit));
+            prop_assert!((1..=i8::MAX).contains(&*n1));
+        }
+
+        #[test]
+        fn edge_strategy(digit in Digit::edge_strategy()) {
+            prop_assert!([0, 1, 8, 9].contains(&*digit));
+        }
+
+        #[test]
+        fn edge_biased_strategy(digit in Digit::edge_biased_strategy()) {
+            prop_assert!((0..=9).contains(&*digit));
+        }
+    }
+
+    fn simplest<T: Arbitrary>() -> T {
+        let mut runner = TestRunner::deterministic();
+        let mut tree = any::<T>().new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        tree.current()
+    }
+
+    #[test]
+    fn shrink_toward_min() {
+        assert_eq!(Bounded::<u8, 3, 9>::new(3), simplest());
+    }
+
+    #[test]
+    fn shrink_toward_zero() {
+        assert_eq!(Bounded::<i8, -5, 5>::new(0), simplest());
+    }
+
+    #[test]
+    fn shrink_negative_toward_min() {
+        assert_eq!(Bounded::<i8, -100, -1>::new(-100), simplest());
+        assert_eq!(
+            Bounded::<i8, { i8::MIN as i128 }, -1>::new(i8::MIN),
+            simplest()
+        );
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary {
+    use super::*;
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn empty() {
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(Ok(Digit::new(0)), Digit::arbitrary(&mut u));
+    }
+
+    #[test]
+    fn in_bounds() {
+        let values: Vec<_> = (0..=u8::MAX)
+            .map(|byte| N1::arbitrary(&mut Unstructured::new(&[byte])).unwrap())
+            .collect();
+        assert!(values.iter().all(|value| (1..=i8::MAX).contains(&**value)));
+        assert!(values.contains(&N1::new(1)));
+        assert!(values.contains(&N1::new(i8::MAX)));
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck {
+    use super::*;
+    use ::bytemuck::checked::{self, CheckedCastError};
+
+    type Byte = Bounded<u8, 0, 9>;
+
+    #[test]
+    fn try_cast_slice() {
+        assert_eq!(
+            Ok(&[Byte::new(1), Byte::new(9)][..]),
+            checked::try_cast_slice::<u8, Byte>(&[1, 9])
+        );
+        assert_eq!(
+            Err(CheckedCastError::InvalidBitPattern),
+            checked::try_cast_slice::<u8, Byte>(&[1, 10])
+        );
+    }
+
+    #[test]
+    fn bytes_of() {
+        assert_eq!(&[7], ::bytemuck::bytes_of(&Byte::new(7)));
+    }
+
+    #[test]
+    fn zeroed() {
+        assert_eq!(Byte::new(0), ::bytemuck::Zeroable::zeroed());
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy {
+    use super::*;
+    use ::zerocopy::IntoBytes;
+
+    type Byte = Bounded<u8, 0, 9>;
+
+    #[test]
+    fn as_bytes() {
+        assert_eq!(&[1, 9], [Byte::new(1), Byte::new(9)].as_bytes());
+    }
+
+    #[test]
+    fn try_ref_from_bytes() {
+        assert_eq!(Some(&Byte::new(9)), Byte::try_ref_from_bytes(&[9]));
+        assert_eq!(None, Byte::try_ref_from_bytes(&[10]));
+        assert_eq!(None, Byte::try_ref_from_bytes(&[1, 2]));
+    }
+
+    #[test]
+    fn try_slice_from_bytes() {
+        assert_eq!(
+            Some(&[Byte::new(1), Byte::new(9)][..]),
+            Byte::try_slice_from_bytes(&[1, 9])
+        );
+        assert_eq!(None, Byte::try_slice_from_bytes(&[1, 10]));
+    }
+
+    #[test]
+    fn misaligned() {
+        type Word = Bounded<u16, 0, 9>;
+
+        let bytes = [0u16, 9, 0].as_bytes();
+        assert_eq!(
+            Some(Word::new(9)),
+            Word::try_ref_from_bytes(&bytes[2..4]).copied()
+        );
+        assert_eq!(None, Word::try_ref_from_bytes(&bytes[1..3]));
+    }
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+    use crate::BoundedNewtype;
+
+    #[derive(BoundedNewtype, Clone, Copy, Debug, PartialEq)]
+    #[bounded(deref)]
+    struct Percent(Bounded<u8, 0, 100>);
+
+    #[derive(BoundedNewtype, Clone, Copy, Debug, PartialEq)]
+    struct Offset(Bounded<i8, -10, 10>);
+
+    #[test]
+    fn ops() {
+        let mut percent = Percent::from(40) + Percent::from(50);
+        assert_eq!(Percent::from(90), percent);
+        percent -= 30;
+        assert_eq!(Percent::from(60), percent);
+        assert_eq!(Percent::from(70), 10 + percent);
+        assert_eq!(Percent::from(30), percent / 2);
+        assert_eq!(Percent::from(15), percent >> 2u32);
+        assert_eq!(Offset::from(-5), -Offset::from(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with out of bounds")]
+    fn add_out_of_bounds() {
+        let _ = Percent::from(60) + Percent::from(50);
+    }
+
+    #[test]
+    fn conversions() {
+        let percent = Percent(Bounded::<u8, 0, 100>::new(42));
+        assert_eq!(42, u8::from(percent));
+        assert_eq!(Bounded::<u8, 0, 100>::new(42), Bounded::from(percent));
+        assert_eq!(42, percent.get());
+    }
+
+    #[test]
+    fn unsigned() {
+        #[derive(BoundedNewtype, Clone, Copy, Debug, PartialEq)]
+        struct Level(Bounded<u8, 0, 9>);
+
+        assert_eq!(Level::from(1), Level::from(9) & 3);
+        assert_eq!(Level::from(8), Level::from(9) - 1);
+    }
+
+    #[test]
+    fn fmt() {
+        let percent = Percent::from(42);
+        assert_eq!("42", percent.to_string());
+        assert_eq!("0x2a", format!("{:#x}", percent));
+        assert_eq!("101010", format!("{:b}", percent));
+    }
+
+    #[cfg(feature = "extra-traits")]
+    #[test]
+    fn num_traits() {
+        use ::num_traits::{Bounded as _, CheckedAdd, One, SaturatingSub, Zero};
+
+        assert_eq!(Percent::from(100), Percent::max_value());
+        assert_eq!(
+            None,
+            CheckedAdd::checked_add(&Percent::from(60), &Percent::from(50))
+        );
+        assert_eq!(
+            Offset::from(-10),
+            SaturatingSub::saturating_sub(&Offset::from(-5), &Offset::from(6))
+        );
+        assert!(Percent::zero().is_zero());
+        assert_eq!(Offset::from(1), Offset::one());
+    }
+}
+
+mod vec {
+    use crate::{BoundedUsize, BoundedVec, LengthError};
+    use core::convert::TryFrom;
+
+    type Batch = BoundedVec<u8, 1, 3>;
+
+    #[test]
+    fn try_from() {
+        assert_eq!(Err(LengthError::TooShort), Batch::try_from(vec![]));
+        assert_eq!(Err(LengthError::TooLong), Batch::try_from(vec![1, 2, 3, 4]));
+        assert_eq!(vec![1, 2], Vec::from(Batch::try_from(vec![1, 2]).unwrap()));
+    }
+
+    #[test]
+    fn len() {
+        let batch = Batch::try_from(vec![1, 2]).unwrap();
+        assert_eq!(BoundedUsize::<1, 3>::new(2), batch.len());
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut batch = Batch::try_from(vec![1, 2]).unwrap();
+        assert_eq!(Ok(()), batch.push(3));
+        assert_eq!(Err(LengthError::TooLong), batch.push(4));
+        assert_eq!(Ok(3), batch.pop());
+        assert_eq!(Ok(2), batch.pop());
+        assert_eq!(Err(LengthError::TooShort), batch.pop());
+        assert_eq!([1], *batch);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut batch = Batch::try_from(vec![1, 3]).unwrap();
+        assert_eq!(Ok(()), batch.insert(1, 2));
+        assert_eq!(Err(LengthError::TooLong), batch.insert(0, 0));
+        assert_eq!([1, 2, 3], *batch);
+        assert_eq!(Ok(1), batch.remove(0));
+        assert_eq!(Ok(2), batch.remove(0));
+        assert_eq!(Err(LengthError::TooShort), batch.remove(0));
+    }
+
+    #[test]
+    fn first_last() {
+        let batch = Batch::try_from(vec![1, 2]).unwrap();
+        assert_eq!(&1, batch.first());
+        assert_eq!(&2, batch.last());
+
+        let empty = BoundedVec::<u8, 0, 3>::default();
+        assert_eq!(None, empty.first());
+    }
+}
+
+mod string {
+    use crate::{BoundedString, BoundedUsize, Chars, LengthError};
+    use core::convert::TryFrom;
+
+    type Username = BoundedString<2, 4>;
+
+    type Label = BoundedString<1, 3, Chars>;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Err(LengthError::TooShort), "a".parse::<Username>());
+        assert_eq!(Err(LengthError::TooLong), "abcde".parse::<Username>());
+        assert_eq!("abc", &*"abc".parse::<Username>().unwrap());
+    }
+
+    #[test]
+    fn len() {
+        let label = Label::try_from("äöü".to_owned()).unwrap();
+        assert_eq!(BoundedUsize::<1, 3>::new(3), label.len());
+        assert_eq!(
+            Err(LengthError::TooLong),
+            BoundedString::<1, 3>::try_from("äö".to_owned())
+        );
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut username: Username = "abc".parse().unwrap();
+        assert_eq!(Ok(()), username.push('d'));
+        assert_eq!(Err(LengthError::TooLong), username.push('e'));
+        assert_eq!(
+            Err(LengthError::TooLong),
+            Username::try_from("ab".to_owned()).unwrap().push_str("cde")
+        );
+        assert_eq!(Ok('d'), username.pop());
+        assert_eq!(Ok('c'), username.pop());
+        assert_eq!(Err(LengthError::TooShort), username.pop());
+        assert_eq!("ab", username.to_string());
+    }
+
+    #[test]
+    fn chars() {
+        let mut label: Label = "ä".parse().unwrap();
+        assert_eq!(Ok(()), label.push_str("öü"));
+        assert_eq!(Err(LengthError::TooLong), label.push('a'));
+        assert_eq!(Err(LengthError::TooShort), label.truncate(0));
+        assert_eq!(Ok(()), label.truncate(2));
+        assert_eq!("ä", label.as_str());
+    }
+}
+
+mod index {
+    use crate::{ArrayIndex, Bounded, EnumerateBounded};
+
+    #[test]
+    fn index() {
+        let mut nibbles = [0u8; 16];
+        let index = Bounded::<usize, 0, 15>::new(15);
+        nibbles[index] = 7;
+        assert_eq!(7, nibbles[index]);
+        assert_eq!(0, [1, 0][Bounded::<usize, 1, 1>::new(1)]);
+    }
+
+    #[test]
+    fn enumerate_bounded() {
+        let digits = [3, 1, 4];
+        let indices: Vec<ArrayIndex<3>> =
+            digits.enumerate_bounded().map(|(index, _)| index).collect();
+        assert_eq!(
+            vec![
+                ArrayIndex::<3>::new(0),
+                ArrayIndex::<3>::new(1),
+                ArrayIndex::<3>::new(2)
+            ],
+            indices
+        );
+        for (index, digit) in digits.enumerate_bounded() {
+            assert_eq!(*digit, digits[index]);
+        }
+    }
+}
+
+mod map {
+    use super::*;
+    use crate::BoundedMap;
+
+    #[test]
+    fn from_fn() {
+        let squares = BoundedMap::<Digit, i32>::from_fn(|digit| i32::from(digit.get()).pow(2));
+        assert_eq!(81, squares[Digit::new(9)]);
+        assert_eq!([0, 1, 4, 9, 16, 25, 36, 49, 64, 81], *squares.values());
+    }
+
+    #[test]
+    fn index_mut() {
+        type Offset = Bounded<i8, -2, 2>;
+
+        let mut counts = BoundedMap::<Offset, u32>::default();
+        counts[Offset::new(-2)] += 1;
+        counts[Offset::new(2)] += 2;
+        assert_eq!([1, 0, 0, 0, 2], counts.into_values());
+    }
+
+    #[test]
+    fn iter() {
+        type Celsius = Bounded<i16, -3, -1>;
+
+        let names = BoundedMap::<Celsius, _>::from(["a", "b", "c"]);
+        let entries: Vec<_> = names
+            .iter()
+            .map(|(key, &value)| (key.get(), value))
+            .collect();
+        assert_eq!(vec![(-3, "a"), (-2, "b"), (-1, "c")], entries);
+    }
+
+    #[test]
+    fn map() {
+        let digits = BoundedMap::<Bounded<u128, 7, 8>, u8>::default();
+        let keys = digits.map(|key, value| key.get() + u128::from(value));
+        assert_eq!([7, 8], keys.into_values());
+    }
+
+    #[test]
+    #[should_panic(expected = "index is out of bounds")]
+    fn from_index_out_of_bounds() {
+        let _ = <Digit as crate::Key>::from_index(10);
+    }
+}
+
+mod set {
+    use super::*;
+    use crate::BoundedSet;
+
+    type Hour = Bounded<u8, 0, 23>;
+
+    type Day = Bounded<u8, 1, 31>;
+
+    fn hours(hours: &[u8]) -> BoundedSet<Hour> {
+        hours.iter().map(|&hour| Hour::new(hour)).collect()
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut days = BoundedSet::<Day>::new();
+        assert!(days.insert(Day::new(31)));
+        assert!(!days.insert(Day::new(31)));
+        assert!(days.contains(Day::new(31)));
+        assert!(!days.contains(Day::new(1)));
+        assert_eq!(1, days.len());
+        assert!(days.remove(Day::new(31)));
+        assert!(!days.remove(Day::new(31)));
+        assert!(days.is_empty());
+    }
+
+    #[test]
+    fn operators() {
+        let morning = hours(&[6, 7, 8, 9]);
+        let work = hours(&[9, 10, 11]);
+        assert_eq!(hours(&[6, 7, 8, 9, 10, 11]), morning | work);
+        assert_eq!(hours(&[9]), morning & work);
+        assert_eq!(hours(&[6, 7, 8, 10, 11]), morning ^ work);
+        assert_eq!(hours(&[6, 7, 8]), morning - work);
+        assert_eq!(20, (!morning).len());
+        assert_eq!(BoundedSet::full(), morning | !morning);
+    }
+
+    #[test]
+    fn iter() {
+        type Wide = Bounded<i16, -70, 70>;
+
+        let set: BoundedSet<Wide> = [70, -70, 0, 63, -7]
+            .iter()
+            .map(|&key| Wide::new(key))
+            .collect();
+        let keys: Vec<_> = set.iter().map(Wide::get).collect();
+        assert_eq!(vec![-70, -7, 0, 63, 70], keys);
+        assert_eq!(141, BoundedSet::<Wide>::full().iter().count());
+        assert_eq!("{Bounded(1), Bounded(2)}", format!("{:?}", hours(&[2, 1])));
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars {
+    use super::*;
+    use ::schemars::{schema_for, JsonSchema};
+    use serde_json::json;
+
+    #[test]
+    fn json_schema() {
+        assert_eq!(
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Bounded_i32_-100_-1",
+                "type": "integer",
+                "format": "int32",
+                "minimum": -100,
+                "maximum": -1
+            }),
+            schema_for!(Bounded<i32, -100, -1>).to_value()
+        );
+    }
+
+    #[test]
+    fn schema_name() {
+        assert_eq!("Bounded_u8_0_9", Bounded::<u8, 0, 9>::schema_name());
+        assert_ne!(
+            Bounded::<u8, 0, 9>::schema_id(),
+            Bounded::<u8, 0, 10>::schema_id()
+        );
+    }
+
+    #[test]
+    fn unrepresentable() {
+        let schema = schema_for!(Bounded<u128, 1, { u128::MAX as i128 }>);
+        assert_eq!(Some(&json!(1)), schema.get("minimum"));
+        assert_eq!(None, schema.get("maximum"));
+    }
+}
+
+#[cfg(feature = "clap")]
+mod clap {
+    use super::*;
+    use ::clap::error::{ContextKind, ContextValue, ErrorKind};
+    use ::clap::{value_parser, Arg, Command};
+
+    type Level = Bounded<u8, 0, 9>;
+
+    type Port = Bounded<u16, 1024, { u16::MAX as _ }>;
+
+    fn command() -> Command {
+        Command::new("compress")
+            .arg(
+                Arg::new("level")
+                    .long("level")
+                    .value_parser(value_parser!(Level)),
+            )
+            .arg(
+                Arg::new("port")
+                    .long("port")
+                    .value_parser(value_parser!(Port)),
+            )
+    }
+
+    #[test]
+    fn value_parser() {
+        let matches = command()
+            .try_get_matches_from(["compress", "--level", "7"])
+            .unwrap();
+        assert_eq!(Some(&Level::new(7)), matches.get_one::<Level>("level"));
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let error = command()
+            .try_get_matches_from(["compress", "--level", "10"])
+            .unwrap_err();
+        assert_eq!(ErrorKind::ValueValidation, error.kind());
+        assert!(error
+            .to_string()
+            .contains("invalid value '10' for '--level <level>': 10 is not in 0..=9"));
+    }
+
+    #[test]
+    fn range_in_context() {
+        let error = command()
+            .try_get_matches_from(["compress", "--port", "80"])
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("invalid value '80' for '--port <port>': 80 is not in 1024..=65535"));
+        assert_eq!(
+            Some(&ContextValue::String("80".to_owned())),
+            error.get(ContextKind::InvalidValue)
+        );
+        assert_eq!(
+            Some(&ContextValue::StyledStrs(vec![
+                "expected a value in 1024..=65535".into()
+            ])),
+            error.get(ContextKind::Suggested)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn invalid_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let error = command()
+            .try_get_matches_from([
+                OsStr::new("compress"),
+                OsStr::new("--level"),
+                OsStr::from_bytes(&[0xff]),
+            ])
+            .unwrap_err();
+        assert_eq!(ErrorKind::InvalidUtf8, error.kind());
+    }
+
+    #[test]
+    fn invalid_digit() {
+        let error = command()
+            .try_get_matches_from(["compress", "--level", "x"])
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("invalid value 'x' for '--level <level>': invalid digit found in string"));
+    }
+
+    #[test]
+    fn help() {
+        let help = command().render_help().to_string();
+        assert!(
+            help.contains("[possible values: 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]"),
+            "{}",
+            help
+        );
+        assert_eq!(1, help.matches("possible values").count(), "{}", help);
     }
 }
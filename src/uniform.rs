@@ -0,0 +1,8 @@
+use rand::distributions::uniform::UniformInt;
+
+/// The back-end implementing `UniformSampler` for bounded integers
+///
+/// Samples are always taken between the given bounded endpoints, so they are
+/// never out of bounds.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformBounded<T, const MIN: i128, const MAX: i128>(pub(crate) UniformInt<T>);
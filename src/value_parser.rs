@@ -0,0 +1,27 @@
+use core::marker::PhantomData;
+
+/// The clap value parser of bounded integers
+///
+/// Up to 16 values in bounds are listed as the possible values in the help.
+/// The range of out of bounds values is named in the error, as a
+/// `ContextKind::Suggested` tip, and the parser displays as `MIN..=MAX` for
+/// the help of larger ranges:
+///
+/// ```
+/// use bounded::{Bounded, BoundedValueParser};
+/// use clap::{Arg, Command};
+///
+/// type Port = Bounded<u16, 1024, { u16::MAX as _ }>;
+///
+/// let parser = BoundedValueParser::<u16, 1024, { u16::MAX as _ }>::default();
+/// let command = Command::new("serve").arg(
+///     Arg::new("port")
+///         .long("port")
+///         .help(format!("Port in {}", parser))
+///         .value_parser(parser),
+/// );
+/// let matches = command.get_matches_from(["serve", "--port", "8080"]);
+/// assert_eq!(Some(&Port::new(8080)), matches.get_one::<Port>("port"));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BoundedValueParser<T, const MIN: i128, const MAX: i128>(PhantomData<T>);
@@ -0,0 +1,159 @@
+use crate::{Assert, Bounded, BoundedUsize, IsTrue, LengthError};
+use core::convert::TryFrom;
+use core::ops::{Deref, DerefMut};
+
+/// Vector whose length is bounded by closed interval
+///
+/// The elements can be mutated in place through the slice it dereferences
+/// to, but only the methods checking the bounds change its length.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BoundedVec<T, const MIN_LEN: usize, const MAX_LEN: usize>(Vec<T>);
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> BoundedVec<T, MIN_LEN, MAX_LEN> {
+    /// Fails to compile when `MIN_LEN` is greater than `MAX_LEN`
+    const VALID: () = assert!(MIN_LEN <= MAX_LEN, "MIN_LEN is greater than MAX_LEN");
+
+    pub fn len(&self) -> BoundedUsize<MIN_LEN, MAX_LEN> {
+        Bounded(self.0.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Appends an element, failing when the vector is full
+    pub fn push(&mut self, value: T) -> Result<(), LengthError> {
+        if self.0.len() == MAX_LEN {
+            return Err(LengthError::TooLong);
+        }
+        self.0.push(value);
+        Ok(())
+    }
+
+    /// Removes the last element, failing when the vector can't shrink
+    pub fn pop(&mut self) -> Result<T, LengthError> {
+        if self.0.len() == MIN_LEN {
+            return Err(LengthError::TooShort);
+        }
+        self.0.pop().ok_or(LengthError::TooShort)
+    }
+
+    /// Inserts an element at `index`, failing when the vector is full
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), LengthError> {
+        if self.0.len() == MAX_LEN {
+            return Err(LengthError::TooLong);
+        }
+        self.0.insert(index, value);
+        Ok(())
+    }
+
+    /// Removes the element at `index`, failing when the vector can't shrink
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Result<T, LengthError> {
+        if self.0.len() == MIN_LEN {
+            return Err(LengthError::TooShort);
+        }
+        Ok(self.0.remove(index))
+    }
+}
+
+/// The vector is never empty, so these shadow the slice methods returning
+/// `Option`
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> BoundedVec<T, MIN_LEN, MAX_LEN>
+where
+    Assert<{ MIN_LEN >= 1 }>: IsTrue,
+{
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+}
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Default for BoundedVec<T, MIN_LEN, MAX_LEN>
+where
+    Assert<{ MIN_LEN == 0 }>: IsTrue,
+{
+    fn default() -> Self {
+        let () = Self::VALID;
+        Self(Vec::new())
+    }
+}
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Deref for BoundedVec<T, MIN_LEN, MAX_LEN> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> DerefMut for BoundedVec<T, MIN_LEN, MAX_LEN> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> From<BoundedVec<T, MIN_LEN, MAX_LEN>>
+    for Vec<T>
+{
+    fn from(from: BoundedVec<T, MIN_LEN, MAX_LEN>) -> Self {
+        from.0
+    }
+}
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> TryFrom<Vec<T>>
+    for BoundedVec<T, MIN_LEN, MAX_LEN>
+{
+    type Error = LengthError;
+
+    fn try_from(from: Vec<T>) -> Result<Self, Self::Error> {
+        let () = Self::VALID;
+        if from.len() < MIN_LEN {
+            Err(LengthError::TooShort)
+        } else if from.len() > MAX_LEN {
+            Err(LengthError::TooLong)
+        } else {
+            Ok(Self(from))
+        }
+    }
+}
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> IntoIterator
+    for BoundedVec<T, MIN_LEN, MAX_LEN>
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const MIN_LEN: usize, const MAX_LEN: usize> IntoIterator
+    for &'a BoundedVec<T, MIN_LEN, MAX_LEN>
+{
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}